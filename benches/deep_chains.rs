@@ -0,0 +1,30 @@
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use micro_errors::{set_capture, ErrorLink_, LinkMessage, NoCapture};
+
+/// Links per chain, about that of an error passed up through a layered service.
+const DEPTH: usize = 16;
+
+fn literal_messages(c: &mut Criterion) {
+    // Capturing backtraces would dwarf what is being compared.
+    set_capture::<NoCapture>();
+    let mut group = c.benchmark_group("deep_chains");
+    group.bench_function("string", |b| b.iter(|| {
+        let mut error_link = ErrorLink_::new_string("Underlying error.");
+        for _ in 0..DEPTH {
+            error_link = error_link.link::<String>(black_box("Higher level error."));
+        }
+        error_link
+    }));
+    group.bench_function("link_message", |b| b.iter(|| {
+        let mut error_link = ErrorLink_::new_message("Underlying error.");
+        for _ in 0..DEPTH {
+            error_link = error_link.link::<LinkMessage>(black_box("Higher level error."));
+        }
+        error_link
+    }));
+    group.finish();
+}
+
+criterion_group!(benches, literal_messages);
+criterion_main!(benches);
@@ -0,0 +1,110 @@
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::fmt::Display;
+use std::sync::Arc;
+use crate::{Attachments, Capture, EndCapture, ErrorLink_, Frame, NextLink};
+
+/// The innermost error of an `anyhow::Error` made from an `ErrorLink_`.
+#[derive(Debug)]
+struct AnyhowRoot(String, Option<Backtrace>);
+
+impl Display for AnyhowRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for AnyhowRoot {
+    #[cfg(feature = "nightly")]
+    fn provide<'a>(&'a self, request: &mut std::error::Request<'a>) {
+        if let Some(backtrace) = &self.1 {
+            request.provide_ref::<Backtrace>(backtrace);
+        }
+    }
+}
+
+/// The `Backtrace` of an `anyhow::Error` not made by `into_anyhow`, which `anyhow` only lends out,
+/// so it is kept as rendered.
+struct AnyhowBacktrace(String);
+
+impl Capture for AnyhowBacktrace {
+    fn capture() -> Self {
+        Self(Backtrace::capture().to_string())
+    }
+
+    fn frames(&self) -> Option<Vec<Frame>> {
+        Some(Frame::parse_all(&self.0))
+    }
+}
+
+impl Display for AnyhowBacktrace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<Payload: Display> ErrorLink_<Payload> {
+    /// Each link becomes a context of the `anyhow::Error`. The `Backtrace` is handed over where
+    /// `anyhow` can request it, i.e., under `nightly`, otherwise `anyhow` captures its own.
+    /// Attachments are dropped, and the branches of a `NextLink::Many` are joined into one, as are
+    /// the links of a `NextLink::Shared` still shared elsewhere.
+    ///
+    /// Is a method rather than `From`, as `anyhow` already has `impl<E: StdError> From<E> for
+    /// anyhow::Error`, which an `impl From<ErrorLink_<Payload>> for anyhow::Error` conflicts with.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        let mut messages = vec![self.0.to_string()];
+        let mut next_link = self.1;
+        let anyhow_root = loop {
+            next_link = match next_link {
+                NextLink::None(end_capture) => {
+                    let root_message = messages.pop().expect("has at least the first");
+                    break AnyhowRoot(root_message, end_capture.into_backtrace());
+                },
+                NextLink::Some(error_link) => {
+                    messages.push(error_link.0.into_owned());
+                    error_link.1
+                },
+                NextLink::Many(error_links) => {
+                    let branches = error_links
+                        .iter()
+                        .map(|error_link| format!("{error_link:#}"))
+                        .collect::<Vec<_>>();
+                    break AnyhowRoot(format!("[{}]", branches.join("; ")), None);
+                }
+                NextLink::Shared(error_link) => match Arc::try_unwrap(error_link) {
+                    Ok(error_link) => {
+                        messages.push(error_link.0.into_owned());
+                        error_link.1
+                    },
+                    Err(error_link) => break AnyhowRoot(format!("{error_link:#}"), None)
+                }
+            }
+        };
+
+        messages
+            .into_iter()
+            .rev()
+            .fold(anyhow::Error::new(anyhow_root), |anyhow_error, message| anyhow_error.context(message))
+    }
+}
+
+impl ErrorLink_<String> {
+    /// Each error of `anyhow_error.chain()` becomes a link. The `Backtrace` is handed back if the
+    /// `anyhow::Error` was made by `into_anyhow`, otherwise that of `anyhow` is kept as rendered,
+    /// as it is only lent out by reference.
+    pub fn from_anyhow(anyhow_error: anyhow::Error) -> Self {
+        let mut messages = anyhow_error.chain().map(ToString::to_string).collect::<Vec<_>>();
+        let anyhow_backtrace = match anyhow_error.backtrace().status() {
+            BacktraceStatus::Captured => EndCapture::new(AnyhowBacktrace(anyhow_error.backtrace().to_string())),
+            _ => EndCapture::from(Backtrace::disabled())
+        };
+        let end_capture = match anyhow_error.downcast::<AnyhowRoot>() {
+            Ok(AnyhowRoot(_, Some(end_backtrace))) => EndCapture::from(end_backtrace),
+            _ => anyhow_backtrace
+        };
+        let root_message = messages.pop().expect("chain has at least the error itself");
+        let error_link = ErrorLink_(root_message, NextLink::None(end_capture), Attachments::capture());
+        messages.into_iter().rev().fold(error_link, |error_link, message| {
+            ErrorLink_(message, NextLink::Some(Box::new(error_link.into_lower())), Attachments::new())
+        })
+    }
+}
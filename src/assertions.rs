@@ -0,0 +1,145 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Write};
+#[cfg(feature = "std")]
+use std::io;
+use crate::{Attachments, ErrorLink_};
+
+/// What `assert_root_cause!` can compare the root cause, i.e., the last link following the first
+/// branch each time, against.
+pub trait RootCause: core::fmt::Debug {
+    fn is_root_cause(&self, error_message: &str, attachments: &Attachments) -> bool;
+}
+
+impl RootCause for str {
+    fn is_root_cause(&self, error_message: &str, _: &Attachments) -> bool {
+        error_message == self
+    }
+}
+
+impl RootCause for String {
+    fn is_root_cause(&self, error_message: &str, attachments: &Attachments) -> bool {
+        self.as_str().is_root_cause(error_message, attachments)
+    }
+}
+
+impl<T: RootCause + ?Sized> RootCause for &T {
+    fn is_root_cause(&self, error_message: &str, attachments: &Attachments) -> bool {
+        (**self).is_root_cause(error_message, attachments)
+    }
+}
+
+/// Matches when the root cause was linked from an `io::Error` of this kind.
+#[cfg(feature = "std")]
+impl RootCause for io::ErrorKind {
+    fn is_root_cause(&self, _: &str, attachments: &Attachments) -> bool {
+        attachments.get::<io::ErrorKind>() == Some(self)
+    }
+}
+
+fn link_messages<Payload: Display>(error_link: &ErrorLink_<Payload>) -> Vec<String> {
+    core::iter::once(error_link.0.to_string())
+        .chain(error_link.links().map(|error_link| error_link.0.to_string()))
+        .collect()
+}
+
+fn write_messages(report: &mut String, error_messages: &[String]) {
+    for (link_number, error_message) in error_messages.iter().enumerate() {
+        let _ = writeln!(report, "  link no. {link_number}: {error_message:?}");
+    }
+}
+
+#[doc(hidden)]
+pub fn check_chain<Payload: Display>(
+    error_link: &ErrorLink_<Payload>, expected_messages: &[String]
+) -> Result<(), String> {
+    let actual_messages = link_messages(error_link);
+    if actual_messages == expected_messages {
+        return Ok(());
+    }
+
+    let mut report = String::from("Link messages differ (- expected, + actual):\n");
+    for link_number in 0..actual_messages.len().max(expected_messages.len()) {
+        match (expected_messages.get(link_number), actual_messages.get(link_number)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                let _ = writeln!(report, "  link no. {link_number}: {actual:?}");
+            },
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    let _ = writeln!(report, "- link no. {link_number}: {expected:?}");
+                }
+                if let Some(actual) = actual {
+                    let _ = writeln!(report, "+ link no. {link_number}: {actual:?}");
+                }
+            }
+        }
+    }
+    Err(report)
+}
+
+#[doc(hidden)]
+pub fn check_root_cause<Payload: Display>(
+    error_link: &ErrorLink_<Payload>, expected: &(impl RootCause + ?Sized)
+) -> Result<(), String> {
+    let mut root_cause = (error_link.0.to_string(), &error_link.2);
+    let mut next_links = error_link.1.next_links();
+    while let Some(next_link) = next_links.next() {
+        root_cause = (next_link.0.to_string(), &next_link.2);
+        next_links = next_link.1.next_links();
+    }
+    if expected.is_root_cause(&root_cause.0, root_cause.1) {
+        return Ok(());
+    }
+
+    let mut report = format!("Root cause {:?} is not {expected:?}, link messages:\n", root_cause.0);
+    write_messages(&mut report, &link_messages(error_link));
+    Err(report)
+}
+
+#[doc(hidden)]
+pub fn check_link_contains<Payload: Display>(
+    error_link: &ErrorLink_<Payload>, pattern: &str
+) -> Result<(), String> {
+    let error_messages = link_messages(error_link);
+    if error_messages.iter().any(|error_message| error_message.contains(pattern)) {
+        return Ok(());
+    }
+
+    let mut report = format!("No link message contains {pattern:?}, link messages:\n");
+    write_messages(&mut report, &error_messages);
+    Err(report)
+}
+
+/// Asserts the messages of an `ErrorLink_`, and of each link below it depth first, ignoring
+/// backtraces. Panics with a message-by-message diff otherwise.
+#[macro_export]
+macro_rules! assert_chain {
+    ($error_link:expr, [$($error_message:expr),* $(,)?]) => {
+        if let ::core::result::Result::Err(report) = $crate::check_chain(
+            &$error_link, &[$($crate::__alloc::string::ToString::to_string(&$error_message)),*]
+        ) {
+            ::core::panic!("{}", report);
+        }
+    };
+}
+
+/// Asserts the root cause of an `ErrorLink_` against, e.g., a message or an `io::ErrorKind`.
+#[macro_export]
+macro_rules! assert_root_cause {
+    ($error_link:expr, $expected:expr $(,)?) => {
+        if let ::core::result::Result::Err(report) = $crate::check_root_cause(&$error_link, &$expected) {
+            ::core::panic!("{}", report);
+        }
+    };
+}
+
+/// Asserts that the message of some link of an `ErrorLink_` contains `pattern`.
+#[macro_export]
+macro_rules! assert_link_contains {
+    ($error_link:expr, $pattern:expr $(,)?) => {
+        if let ::core::result::Result::Err(report) = $crate::check_link_contains(&$error_link, $pattern) {
+            ::core::panic!("{}", report);
+        }
+    };
+}
@@ -0,0 +1,50 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+#[cfg(feature = "std")]
+use crate::context_scope::ContextScopes;
+#[cfg(feature = "tracing")]
+use crate::tracing_events::capture_span_trace;
+
+/// Arbitrary typed values carried by a link, retrievable by their type.
+#[derive(Debug, Default)]
+pub struct Attachments(Vec<Box<dyn Any + Send + Sync>>);
+
+impl Attachments {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Attachments for a link being created, e.g., holding the messages of the active context
+    /// scopes.
+    pub fn capture() -> Self {
+        #[cfg_attr(not(feature = "std"), allow(unused_mut))]
+        let mut attachments = Attachments::new();
+        #[cfg(feature = "std")]
+        ContextScopes::capture(&mut attachments);
+        #[cfg(feature = "tracing")]
+        capture_span_trace(&mut attachments);
+        attachments
+    }
+
+    pub fn insert<T: Any + Send + Sync>(&mut self, attachment: T) {
+        self.0.push(Box::new(attachment));
+    }
+
+    /// The most recently inserted attachment of type `T`, if any.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.0.iter().rev().find_map(|attachment| attachment.downcast_ref::<T>())
+    }
+
+    pub fn get_mut<T: Any + Send + Sync>(&mut self) -> Option<&mut T> {
+        self.0.iter_mut().rev().find_map(|attachment| attachment.downcast_mut::<T>())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -0,0 +1,39 @@
+use std::io::Read;
+use std::process::ExitCode;
+use micro_errors::{ErrorLink_, ErrorLinkable, RawBacktrace};
+
+/// Symbolizes the raw backtraces within what is read from stdin, e.g., a report or a log, against
+/// the unstripped binary given as the only argument.
+fn main() -> ExitCode {
+    match symbolize() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error_link) => {
+            eprintln!("{error_link:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn symbolize() -> Result<(), ErrorLink_<String>> {
+    let Some(binary) = std::env::args_os().nth(1) else {
+        return Err(ErrorLink_::new("Usage: micro-errors-symbolize <unstripped binary> < report"));
+    };
+    let mut text = String::new();
+    std::io::stdin()
+        .read_to_string(&mut text)
+        .map_err(|e| e.link("Reading stdin failed."))?;
+
+    let raw_backtraces = RawBacktrace::parse_all(&text);
+    if raw_backtraces.is_empty() {
+        return Err(ErrorLink_::new("No raw backtraces were found in stdin."));
+    }
+    for (backtrace_number, raw_backtrace) in raw_backtraces.iter().enumerate() {
+        let frames = raw_backtrace.symbolize(&binary)?;
+        println!("Backtrace no. {backtrace_number}:");
+        for (frame_number, frame) in frames.iter().enumerate() {
+            println!("{frame_number:>4}: {frame}");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,251 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+#[cfg(feature = "std")]
+use core::any::TypeId;
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::backtrace::{Backtrace, BacktraceStatus};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::{Attachments, ErrorLink_, Frame, NextLink};
+
+/// What is captured where a chain starts, i.e., for its `NextLink::None`. Is the std `Backtrace` by
+/// default, see `set_capture` for choosing another, e.g., `NoCapture`.
+pub trait Capture: Display + Send + Sync + 'static {
+    fn capture() -> Self where Self: Sized;
+
+    /// The frames captured, for them to be rendered as those of a std `Backtrace` are, i.e.,
+    /// filtered. Otherwise, i.e., `None`, the `Display` is rendered.
+    fn frames(&self) -> Option<Vec<Frame>> {
+        None
+    }
+
+    /// Whether anything was captured, e.g., for it to be serialized.
+    fn is_captured(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "std")]
+impl Capture for Backtrace {
+    fn capture() -> Self {
+        Backtrace::capture()
+    }
+
+    fn frames(&self) -> Option<Vec<Frame>> {
+        self.is_captured().then(|| Frame::parse_all(&self.to_string()))
+    }
+
+    fn is_captured(&self) -> bool {
+        self.status() == BacktraceStatus::Captured
+    }
+}
+
+/// Captures nothing, for when a chain's start is not worth its cost.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoCapture;
+
+impl Capture for NoCapture {
+    fn capture() -> Self {
+        NoCapture
+    }
+
+    fn is_captured(&self) -> bool {
+        false
+    }
+}
+
+impl Display for NoCapture {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "disabled backtrace")
+    }
+}
+
+/// Captures only the instruction pointers, leaving the symbols to be resolved when rendered.
+#[cfg(feature = "backtrace")]
+pub struct DeferredBacktrace(std::sync::Mutex<backtrace::Backtrace>);
+
+#[cfg(feature = "backtrace")]
+impl Capture for DeferredBacktrace {
+    fn capture() -> Self {
+        Self(std::sync::Mutex::new(backtrace::Backtrace::new_unresolved()))
+    }
+
+    fn frames(&self) -> Option<Vec<Frame>> {
+        let mut backtrace = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        backtrace.resolve();
+        Some(Frame::resolve_all(&backtrace))
+    }
+}
+
+#[cfg(feature = "backtrace")]
+impl Display for DeferredBacktrace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for frame in self.frames().unwrap_or_default() {
+            writeln!(f, "{:>4}: {frame}", frame.number)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+type CaptureFn = fn() -> Box<dyn Capture>;
+
+#[cfg(feature = "std")]
+const STD_BACKTRACE: usize = 0;
+#[cfg(feature = "std")]
+const NO_CAPTURE: usize = 1;
+/// Any other choice is this plus the index of its `CaptureFn` in `CAPTURE_FNS`.
+#[cfg(feature = "std")]
+const FIRST_CAPTURE_FN: usize = 2;
+
+/// What `set_capture` chose, read once per chain started. Neither the std `Backtrace` nor
+/// `NoCapture` need the lock of `CAPTURE_FNS` to be taken.
+#[cfg(feature = "std")]
+static CAPTURE_CHOICE: AtomicUsize = AtomicUsize::new(STD_BACKTRACE);
+
+/// Only ever appended to, so that an index read from `CAPTURE_CHOICE` stays valid.
+#[cfg(feature = "std")]
+static CAPTURE_FNS: RwLock<Vec<(TypeId, CaptureFn)>> = RwLock::new(Vec::new());
+
+/// Makes `C` what is captured where chains start from now on, instead of the std `Backtrace`.
+#[cfg(feature = "std")]
+pub fn set_capture<C: Capture>() {
+    let capture_choice = if TypeId::of::<C>() == TypeId::of::<Backtrace>() {
+        STD_BACKTRACE
+    } else if TypeId::of::<C>() == TypeId::of::<NoCapture>() {
+        NO_CAPTURE
+    } else {
+        let mut capture_fns = CAPTURE_FNS.write().unwrap_or_else(|e| e.into_inner());
+        let capture_index = match capture_fns.iter().position(|(type_id, _)| *type_id == TypeId::of::<C>()) {
+            Some(capture_index) => capture_index,
+            None => {
+                capture_fns.push((TypeId::of::<C>(), || Box::new(C::capture()) as Box<dyn Capture>));
+                capture_fns.len() - 1
+            }
+        };
+        FIRST_CAPTURE_FN + capture_index
+    };
+    CAPTURE_CHOICE.store(capture_choice, Ordering::Release);
+}
+
+/// What was captured where a chain started, i.e., what its `NextLink::None` holds. It is the same
+/// type with or without `std`, only ever holding a std `Backtrace` with it.
+pub struct EndCapture(EndCaptureKind);
+
+enum EndCaptureKind {
+    Nothing,
+    #[cfg(feature = "std")]
+    Backtrace(Backtrace),
+    Other(Box<dyn Capture>)
+}
+
+impl EndCapture {
+    /// Captures what was chosen by `set_capture`, i.e., the std `Backtrace` by default. Without
+    /// `std`, nothing is captured.
+    pub fn capture() -> Self {
+        #[cfg(feature = "std")]
+        match CAPTURE_CHOICE.load(Ordering::Acquire) {
+            STD_BACKTRACE => Self(EndCaptureKind::Backtrace(Backtrace::capture())),
+            NO_CAPTURE => Self::nothing(),
+            capture_choice => {
+                let capture_fns = CAPTURE_FNS.read().unwrap_or_else(|e| e.into_inner());
+                let (_, capture_fn) = capture_fns[capture_choice - FIRST_CAPTURE_FN];
+                drop(capture_fns);
+                Self(EndCaptureKind::Other(capture_fn()))
+            }
+        }
+        #[cfg(not(feature = "std"))]
+        Self::nothing()
+    }
+
+    /// Holds `capture`, which is only boxed when neither `NoCapture` nor the std `Backtrace`.
+    pub fn new<C: Capture>(capture: C) -> Self {
+        let mut capture = Some(capture);
+        let any_capture = &mut capture as &mut dyn Any;
+        if any_capture.is::<Option<NoCapture>>() {
+            return Self::nothing();
+        }
+        #[cfg(feature = "std")]
+        if let Some(backtrace) = any_capture.downcast_mut::<Option<Backtrace>>() {
+            return Self(EndCaptureKind::Backtrace(backtrace.take().expect("only taken here")));
+        }
+        Self(EndCaptureKind::Other(Box::new(capture.expect("only taken above"))))
+    }
+
+    pub const fn nothing() -> Self {
+        Self(EndCaptureKind::Nothing)
+    }
+
+    pub fn as_capture(&self) -> &dyn Capture {
+        match &self.0 {
+            EndCaptureKind::Nothing => &NoCapture,
+            #[cfg(feature = "std")]
+            EndCaptureKind::Backtrace(backtrace) => backtrace,
+            EndCaptureKind::Other(capture) => &**capture
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        match &self.0 {
+            EndCaptureKind::Backtrace(backtrace) => Some(backtrace),
+            _ => None
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn into_backtrace(self) -> Option<Backtrace> {
+        match self.0 {
+            EndCaptureKind::Backtrace(backtrace) => Some(backtrace),
+            _ => None
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Backtrace> for EndCapture {
+    fn from(backtrace: Backtrace) -> Self {
+        Self(EndCaptureKind::Backtrace(backtrace))
+    }
+}
+
+impl Display for EndCapture {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_capture())
+    }
+}
+
+impl core::fmt::Debug for EndCapture {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            EndCaptureKind::Nothing => write!(f, "{NoCapture:?}"),
+            #[cfg(feature = "std")]
+            EndCaptureKind::Backtrace(backtrace) => write!(f, "{backtrace:?}"),
+            EndCaptureKind::Other(capture) => write!(f, "{capture}")
+        }
+    }
+}
+
+impl<Payload: Display> ErrorLink_<Payload> {
+    /// Like `new`, but captures `C` whatever was chosen by `set_capture`.
+    pub fn new_capturing<C: Capture>(error_payload: impl Into<Payload>) -> Self {
+        Self(error_payload.into(), NextLink::None(EndCapture::new(C::capture())), Attachments::capture())
+    }
+
+    /// What was captured where the chain started, following the first branch each time.
+    pub fn end_capture(&self) -> Option<&dyn Capture> {
+        let mut next_link = &self.1;
+        while let Some(error_link) = next_link.next_links().next() {
+            next_link = &error_link.1;
+        }
+        match next_link {
+            NextLink::None(end_capture) => Some(end_capture.as_capture()),
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use crate::{Attachments, ErrorLink_};
+
+thread_local! {
+    /// The messages of the active context scopes, by the identifier of their guard.
+    static CONTEXT_SCOPES: RefCell<Vec<(u64, String)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_SCOPE_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Messages of the context scopes that were active where a link was created, outermost first.
+pub(crate) struct ContextScopes(Vec<String>);
+
+impl ContextScopes {
+    pub(crate) fn of(attachments: &Attachments) -> &[String] {
+        attachments.get::<ContextScopes>().map_or(&[], |context_scopes| &context_scopes.0)
+    }
+
+    pub(crate) fn capture(attachments: &mut Attachments) {
+        let context_scopes = CONTEXT_SCOPES
+            .try_with(|context_scopes| {
+                context_scopes.borrow().iter().map(|(_, message)| message.clone()).collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if !context_scopes.is_empty() {
+            attachments.insert(ContextScopes(context_scopes));
+        }
+    }
+}
+
+impl<Payload: Display> ErrorLink_<Payload> {
+    pub fn context_scopes(&self) -> &[String] {
+        ContextScopes::of(&self.2)
+    }
+}
+
+/// Guard of a context scope, which ends when dropped, even if scopes started after it are still
+/// active.
+#[must_use = "the context scope ends as soon as the guard is dropped"]
+pub struct ContextScope(u64, PhantomData<*const ()>);
+
+/// Starts a context scope, whose message is recorded by every link created on this thread until
+/// the returned guard is dropped. Saves threading `.link(..)` through each call site.
+pub fn context_scope(message: impl Into<String>) -> ContextScope {
+    let scope_id = NEXT_SCOPE_ID.replace(NEXT_SCOPE_ID.get() + 1);
+    CONTEXT_SCOPES.with_borrow_mut(|context_scopes| context_scopes.push((scope_id, message.into())));
+    ContextScope(scope_id, PhantomData)
+}
+
+impl Drop for ContextScope {
+    fn drop(&mut self) {
+        let _ = CONTEXT_SCOPES.try_with(|context_scopes| {
+            let mut context_scopes = context_scopes.borrow_mut();
+            if let Some(scope_index) = context_scopes.iter().rposition(|(scope_id, _)| *scope_id == self.0) {
+                context_scopes.remove(scope_index);
+            }
+        });
+    }
+}
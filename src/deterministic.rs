@@ -0,0 +1,140 @@
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use core::panic::Location;
+use core::sync::atomic::{AtomicU8, Ordering};
+#[cfg(feature = "std")]
+use std::cell::Cell;
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+use crate::Capture;
+#[cfg(feature = "std")]
+use crate::ErrorLink_;
+use crate::frames::render_frames;
+use crate::source_snippets::snippet_frames;
+
+const UNSET: u8 = 0;
+const DISABLED: u8 = 1;
+const ENABLED: u8 = 2;
+
+static DETERMINISTIC: AtomicU8 = AtomicU8::new(UNSET);
+
+#[cfg(feature = "std")]
+thread_local! {
+    static FORCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Makes rendering replace backtraces, paths and line numbers with stable placeholders, e.g., for
+/// golden-file tests. Takes precedence over the `MICRO_ERRORS_DETERMINISTIC` env var.
+pub fn set_deterministic(deterministic: bool) {
+    DETERMINISTIC.store(if deterministic { ENABLED } else { DISABLED }, Ordering::Relaxed);
+}
+
+/// Whether rendering is deterministic, by `set_deterministic` or else by the
+/// `MICRO_ERRORS_DETERMINISTIC` env var being set to anything but "", "0" or "false". Without
+/// `std`, only by `set_deterministic`.
+pub fn is_deterministic() -> bool {
+    #[cfg(feature = "std")]
+    if FORCED.with(Cell::get) {
+        return true;
+    }
+    match DETERMINISTIC.load(Ordering::Relaxed) {
+        DISABLED => false,
+        ENABLED => true,
+        _ => is_deterministic_by_env()
+    }
+}
+
+#[cfg(feature = "std")]
+fn is_deterministic_by_env() -> bool {
+    static BY_ENV: OnceLock<bool> = OnceLock::new();
+    *BY_ENV.get_or_init(|| {
+        std::env::var("MICRO_ERRORS_DETERMINISTIC")
+            .is_ok_and(|value| !matches!(value.as_str(), "" | "0" | "false"))
+    })
+}
+
+#[cfg(not(feature = "std"))]
+fn is_deterministic_by_env() -> bool {
+    false
+}
+
+#[cfg(feature = "std")]
+impl<Payload: Display> ErrorLink_<Payload> {
+    /// Renders as `Display` does, but deterministically whatever the global setting.
+    pub fn to_deterministic_string(&self) -> String {
+        let was_forced = FORCED.replace(true);
+        let rendered = self.to_string();
+        FORCED.set(was_forced);
+        rendered
+    }
+}
+
+pub(crate) fn render_capture(capture: &dyn Capture) -> String {
+    if is_deterministic() {
+        return String::from("<backtrace>\n");
+    }
+    match capture.frames() {
+        Some(frames) => render_frames(&frames, snippet_frames()),
+        None => capture.to_string()
+    }
+}
+
+/// A location without its line and column, and whose path is relative.
+pub(crate) struct StableLocation<'a>(pub(crate) &'a Location<'a>);
+
+impl Display for StableLocation<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !is_deterministic() {
+            return write!(f, "{}", self.0);
+        }
+        write_stable_file(f, self.0.file())?;
+        write!(f, ":<line>:<column>")
+    }
+}
+
+#[cfg(feature = "std")]
+fn write_stable_file(f: &mut core::fmt::Formatter<'_>, file: &str) -> core::fmt::Result {
+    let path = Path::new(file);
+    match path.file_name() {
+        Some(file_name) if path.is_absolute() => write!(f, "<path>/{}", file_name.to_string_lossy()),
+        _ => write!(f, "{file}")
+    }
+}
+
+/// Without `std`, paths are as they were given to the compiler.
+#[cfg(not(feature = "std"))]
+fn write_stable_file(f: &mut core::fmt::Formatter<'_>, file: &str) -> core::fmt::Result {
+    write!(f, "{file}")
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn render_span_trace(span_trace: &tracing_error::SpanTrace) -> String {
+    if !is_deterministic() {
+        return span_trace.to_string();
+    }
+    let mut rendered = String::new();
+    let mut span_number = 0;
+    span_trace.with_spans(|metadata, fields| {
+        rendered.push_str(&format!("{span_number:>4}: {}::{}\n", metadata.target(), metadata.name()));
+        if !fields.is_empty() {
+            rendered.push_str(&format!("           with {fields}\n"));
+        }
+        span_number += 1;
+        true
+    });
+    rendered
+}
+
+/// Asserts an `ErrorLink_` against an `insta` snapshot of its deterministic rendering. Arguments
+/// after the error, e.g., an inline `@"..."` snapshot, are passed on to `insta::assert_snapshot!`.
+#[cfg(feature = "insta")]
+#[macro_export]
+macro_rules! assert_error_snapshot {
+    ($error_link:expr $(, $($snapshot:tt)*)?) => {
+        $crate::__insta::assert_snapshot!(
+            $crate::ErrorLink_::to_deterministic_string(&$error_link) $(, $($snapshot)*)?
+        )
+    };
+}
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 use std::backtrace::Backtrace;
+use crate::{Attachments, ErrorLink_, NextLink};
 
 #[derive(Debug)]
 #[deprecated(since = "0.3.0", note="use `NextLink` instead")]
@@ -81,7 +82,7 @@ impl ErrorChain<String> {
     }
 }
 
-#[allow(deprecated)]
+#[allow(deprecated, clippy::write_with_newline)]
 impl<T: std::fmt::Display> std::fmt::Display for ErrorChain<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Link no. 0: {}\n", self.0)?;
@@ -105,3 +106,23 @@ impl<T: std::fmt::Display> std::fmt::Display for ErrorChain<T> {
         Ok(())
     }
 }
+
+/// Keeps every message and the `Severed` backtrace, so older modules can be migrated one by one.
+#[allow(deprecated)]
+impl From<ErrorLink> for NextLink {
+    fn from(value: ErrorLink) -> Self {
+        match value {
+            ErrorLink::Severed(end_backtrace) => NextLink::None(end_backtrace.into()),
+            ErrorLink::Continued(error_message, next_link) => NextLink::Some(
+                Box::new(ErrorLink_(error_message.into(), (*next_link).into(), Attachments::new()))
+            )
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<T: Display> From<ErrorChain<T>> for ErrorLink_<String> {
+    fn from(value: ErrorChain<T>) -> Self {
+        ErrorLink_(value.0.to_string(), value.1.into(), Attachments::new())
+    }
+}
@@ -0,0 +1,131 @@
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::process::ExitCode;
+use crate::ErrorLink_;
+
+/// A stable identifier of a kind of error, e.g., "E-DB-0042", for its explanation to be looked up
+/// without the source, see `register_error_code!` and `explain_main!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ErrorCode(pub &'static str);
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ErrorCode {
+    pub fn explanation(self) -> Option<&'static Explanation> {
+        explain(self.0)
+    }
+}
+
+/// Payloads that have an `ErrorCode`, e.g., the variants of an enum of errors.
+pub trait HasErrorCode {
+    fn error_code(&self) -> ErrorCode;
+}
+
+impl<Payload: Display> ErrorLink_<Payload> {
+    /// Attaches the code of the payload, for it to be kept once linked on top of.
+    pub fn coded(self) -> Self where Payload: HasErrorCode {
+        let error_code = self.0.error_code();
+        self.attach(error_code)
+    }
+
+    /// The code of this link, or else of the nearest link below it to have one.
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        self.request_ref::<ErrorCode>().copied()
+    }
+}
+
+/// The long form of what an `ErrorCode` means, as registered by `register_error_code!`.
+#[derive(Debug)]
+pub struct Explanation {
+    pub code: ErrorCode,
+    /// A line of what went wrong.
+    pub summary: &'static str,
+    /// E.g., the usual causes, and what to do about them.
+    pub details: &'static str,
+    pub doc_url: Option<&'static str>
+}
+
+/// Writes as `rustc --explain` does, i.e., the summary then the details.
+impl Display for Explanation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "{}: {}", self.code, self.summary)?;
+        if !self.details.is_empty() {
+            writeln!(f, "\n{}", self.details.trim())?;
+        }
+        if let Some(doc_url) = self.doc_url {
+            writeln!(f, "\nSee {doc_url}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Every explanation registered by `register_error_code!` in the crates linked together.
+#[doc(hidden)]
+#[linkme::distributed_slice]
+pub static ERROR_CODES: [Explanation];
+
+pub fn explanations() -> &'static [Explanation] {
+    &ERROR_CODES
+}
+
+/// The explanation registered for `error_code`, e.g., as given by a customer.
+pub fn explain(error_code: &str) -> Option<&'static Explanation> {
+    ERROR_CODES.iter().find(|explanation| explanation.code.0 == error_code.trim())
+}
+
+/// Registers the `Explanation` of an error code as the static `$name`, from whichever crate, for
+/// it to be collected when linking.
+#[macro_export]
+macro_rules! register_error_code {
+    ($name:ident = $explanation:expr) => {
+        #[$crate::__linkme::distributed_slice($crate::ERROR_CODES)]
+        #[linkme(crate = $crate::__linkme)]
+        static $name: $crate::Explanation = $explanation;
+    };
+}
+
+/// The `main` of a binary explaining error codes, see `explain_main!`.
+#[cfg(feature = "std")]
+pub fn explain_main() -> ExitCode {
+    let mut args = std::env::args();
+    let binary = args.next().unwrap_or_else(|| String::from("explain"));
+    let Some(error_code) = args.next() else {
+        eprintln!("Usage: {binary} <error code>, or --list for every code.");
+        return ExitCode::FAILURE;
+    };
+    if error_code == "--list" {
+        for explanation in explanations() {
+            println!("{}: {}", explanation.code, explanation.summary);
+        }
+        return ExitCode::SUCCESS;
+    }
+    match explain(&error_code) {
+        Some(explanation) => {
+            print!("{explanation}");
+            ExitCode::SUCCESS
+        },
+        None => {
+            eprintln!("No explanation is registered for {error_code}.");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Expands to the `main` of a binary that prints the explanation of the error code given, as
+/// `rustc --explain` does, or of every code given `--list`. The codes are collected when linking,
+/// so only those registered by crates linked into the binary are known, which is why an
+/// application ships its own, e.g., `src/bin/explain.rs` being `micro_errors::explain_main!();`.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! explain_main {
+    () => {
+        fn main() -> ::std::process::ExitCode {
+            $crate::explain_main()
+        }
+    };
+}
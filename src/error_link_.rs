@@ -1,52 +1,192 @@
-use std::any::Any;
-use std::backtrace::Backtrace;
-use std::fmt::{Debug, Display};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt::{Debug, Display, Write};
+use core::hash::{Hash, Hasher};
+use crate::Attachments;
+#[cfg(feature = "codes")]
+use crate::ErrorCode;
+use crate::capture::EndCapture;
+#[cfg(feature = "std")]
+use crate::context_scope::ContextScopes;
+use crate::deterministic::{is_deterministic, render_capture, StableLocation};
+#[cfg(feature = "tracing")]
+use crate::deterministic::render_span_trace;
+use crate::return_trace::ReturnTrace;
+use crate::source_snippets::{snippet_frames, write_snippet};
+use crate::suppressed::Suppressed;
+
+/// The message of a link below another, which is borrowed rather than allocated when a literal.
+pub type LinkMessage = Cow<'static, str>;
 
 #[derive(Debug)]
 pub enum NextLink {
-    None(Backtrace),
-    Some(Box<ErrorLink_<String>>)
+    None(EndCapture),
+    Some(Box<ErrorLink_<LinkMessage>>),
+    Many(Vec<ErrorLink_<LinkMessage>>),
+    /// A link that other chains may also be on top of, see `ErrorLink_::shared`.
+    Shared(Arc<ErrorLink_<LinkMessage>>)
 }
 
 #[derive(Debug)]
-pub struct ErrorLink_<Payload: Display>(pub Payload, pub NextLink);
+pub struct ErrorLink_<Payload: Display>(pub Payload, pub NextLink, pub Attachments);
 
 impl<Payload: Display> ErrorLink_<Payload> {
     pub fn new(error_payload: impl Into<Payload>) -> Self {
-        Self(error_payload.into(), NextLink::None(Backtrace::capture()))
+        Self(error_payload.into(), NextLink::None(EndCapture::capture()), Attachments::capture())
+    }
+
+    pub fn new_many<FromPayload: Display + 'static>(
+        error_payload: impl Into<Payload>, error_links: impl IntoIterator<Item = ErrorLink_<FromPayload>>
+    ) -> Self {
+        Self(error_payload.into(), error_links.into_iter().collect(), Attachments::new())
     }
 
     pub fn replace<NewPayload: Display>(
         self, error_payload: impl Into<NewPayload>
     ) -> ErrorLink_<NewPayload>{
-        ErrorLink_(error_payload.into(), self.1)
+        ErrorLink_(error_payload.into(), self.1, self.2)
     }
 
-    pub fn link<ToPayload: Display>(self, error_payload: impl Into<ToPayload>) -> ErrorLink_<ToPayload> {
+    pub fn link<ToPayload: Display>(self, error_payload: impl Into<ToPayload>) -> ErrorLink_<ToPayload>
+    where
+        Payload: 'static
+    {
         Self::link_fn(error_payload)(self)
     }
 
-    pub fn link_fn<ToPayload: Display>(error_payload: impl Into<ToPayload>) -> impl FnOnce(Self) -> ErrorLink_<ToPayload> {
+    pub fn link_fn<ToPayload: Display>(error_payload: impl Into<ToPayload>) -> impl FnOnce(Self) -> ErrorLink_<ToPayload>
+    where
+        Payload: 'static
+    {
         move |underlying_error| {
-            let next_link = Box::new(ErrorLink_(underlying_error.0.to_string(), underlying_error.1));
-            ErrorLink_(error_payload.into(), NextLink::Some(next_link))
+            let next_link = Box::new(underlying_error.into_lower());
+            ErrorLink_(error_payload.into(), NextLink::Some(next_link), Attachments::new())
         }
     }
 
+    /// This link as one below another, whose payload is moved rather than formatted when it is
+    /// already a string.
+    pub(crate) fn into_lower(self) -> ErrorLink_<LinkMessage> where Payload: 'static {
+        let mut payload = Some(self.0);
+        let payload_any = &mut payload as &mut dyn Any;
+        let link_message = if let Some(link_message) = payload_any.downcast_mut::<Option<LinkMessage>>() {
+            link_message.take().expect("was just set")
+        } else if let Some(error_message) = payload_any.downcast_mut::<Option<String>>() {
+            Cow::Owned(error_message.take().expect("was just set"))
+        } else if let Some(error_message) = payload_any.downcast_mut::<Option<&'static str>>() {
+            Cow::Borrowed(error_message.take().expect("was just set"))
+        } else {
+            Cow::Owned(payload.expect("was just set").to_string())
+        };
+        ErrorLink_(link_message, self.1, self.2)
+    }
+
     pub fn as_link<ToPayload: From<Payload> + Display>(self) -> ErrorLink_<ToPayload> {
-        ErrorLink_(self.0.into(), self.1)
+        ErrorLink_(self.0.into(), self.1, self.2)
+    }
+
+    pub fn attach<T: Any + Send + Sync>(mut self, attachment: T) -> Self {
+        self.2.insert(attachment);
+        self
+    }
+
+    /// Searches this link, then each link below it, for an attachment of type `T`.
+    pub fn request_ref<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.2.get::<T>().or_else(|| self.links().find_map(|error_link| error_link.2.get::<T>()))
+    }
+
+    /// Iterates over the links below this one, depth first, nearest first.
+    pub fn links(&self) -> impl Iterator<Item = &ErrorLink_<LinkMessage>> {
+        let mut unvisited: Vec<_> = self.1.next_links().rev().collect();
+        core::iter::from_fn(move || {
+            let error_link = unvisited.pop()?;
+            unvisited.extend(error_link.1.next_links().rev());
+            Some(error_link)
+        })
+    }
+}
+
+impl NextLink {
+    /// The links directly below, of which there are several only for `NextLink::Many`.
+    pub fn next_links(&self) -> core::slice::Iter<'_, ErrorLink_<LinkMessage>> {
+        match self {
+            NextLink::None(_) => [].iter(),
+            NextLink::Some(error_link) => core::slice::from_ref(&**error_link).iter(),
+            NextLink::Many(error_links) => error_links.iter(),
+            NextLink::Shared(error_link) => core::slice::from_ref(&**error_link).iter()
+        }
+    }
+}
+
+/// Compares the links below, ignoring their backtraces and attachments, and whether shared.
+impl PartialEq for NextLink {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(self, NextLink::Many(_)) == matches!(other, NextLink::Many(_))
+            && self.next_links().as_slice() == other.next_links().as_slice()
+    }
+}
+
+impl Eq for NextLink {}
+
+impl Hash for NextLink {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        matches!(self, NextLink::Many(_)).hash(state);
+        self.next_links().as_slice().hash(state);
+    }
+}
+
+/// Compares the payload and the messages of the links below, ignoring backtraces and attachments.
+impl<Payload: Display + PartialEq> PartialEq for ErrorLink_<Payload> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+impl<Payload: Display + Eq> Eq for ErrorLink_<Payload> {}
+
+impl<Payload: Display + Hash> Hash for ErrorLink_<Payload> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+    }
+}
+
+impl<Payload: Display + 'static> FromIterator<ErrorLink_<Payload>> for NextLink {
+    fn from_iter<T: IntoIterator<Item = ErrorLink_<Payload>>>(error_links: T) -> Self {
+        NextLink::Many(error_links.into_iter().map(ErrorLink_::into_lower).collect())
+    }
+}
+
+impl<Payload: Display + 'static> From<Vec<ErrorLink_<Payload>>> for NextLink {
+    fn from(error_links: Vec<ErrorLink_<Payload>>) -> Self {
+        error_links.into_iter().collect()
     }
 }
 
 impl ErrorLink_<String> {
+    /// Takes anything into a `String`, including a `LinkMessage`, as the top link of a chain owns
+    /// its payload. See `new_message` for a link that borrows a literal instead.
     pub fn new_string(error_message: impl Into<String>) -> Self {
-        Self(error_message.into(), NextLink::None(Backtrace::capture()))
+        Self(error_message.into(), NextLink::None(EndCapture::capture()), Attachments::capture())
+    }
+}
+
+impl ErrorLink_<LinkMessage> {
+    /// Like `new_string`, but a literal message is not allocated, nor is it when linked on top of.
+    pub fn new_message(error_message: impl Into<LinkMessage>) -> Self {
+        Self(error_message.into(), NextLink::None(EndCapture::capture()), Attachments::capture())
     }
 }
 
 pub trait ErrorLinkable<Self_, Payload: Display>: Any + Display {
     fn link(self, error_payload: impl Into<Payload>) -> ErrorLink_<Payload>;
     fn link_fn(error_payload: impl Into<Payload>) -> impl FnOnce(Self_) -> ErrorLink_<Payload>;
+    #[allow(clippy::wrong_self_convention)]
     fn as_link(self) -> ErrorLink_<Payload>;
 }
 
@@ -58,51 +198,201 @@ impl<T: Any + Display> ErrorLinkable<T, String> for T {
     fn link_fn(error_message: impl Into<String>) -> impl FnOnce(Self) -> ErrorLink_<String> {
         move |underlying_error| {
             let next_link = Box::new(ErrorLink_(
-                String::from(underlying_error.to_string()), 
-                NextLink::None(Backtrace::capture())
+                Cow::Owned(underlying_error.to_string()),
+                NextLink::None(EndCapture::capture()),
+                capture_attachments(&underlying_error)
             ));
-            ErrorLink_(error_message.into(), NextLink::Some(next_link))
+            ErrorLink_(error_message.into(), NextLink::Some(next_link), Attachments::new())
         }
     }
 
     fn as_link(self) -> ErrorLink_<String> {
         ErrorLink_(
             self.to_string(),
-            NextLink::None(Backtrace::capture())
+            NextLink::None(EndCapture::capture()),
+            capture_attachments(&self)
         )
     }
 }
 
+/// Only the message of a non `ErrorLink_` is kept, so the kind of an `io::Error` is attached too.
+#[cfg(feature = "std")]
+pub(crate) fn capture_attachments(error: &dyn Any) -> Attachments {
+    let mut attachments = Attachments::capture();
+    if let Some(io_error) = error.downcast_ref::<std::io::Error>() {
+        attachments.insert(io_error.kind());
+    }
+    attachments
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn capture_attachments(_: &dyn Any) -> Attachments {
+    Attachments::capture()
+}
+
+/// Keeps an `ErrorLink_<String>`, or `ErrorLink_<LinkMessage>`, as is, rather than have its
+/// report flattened into one link.
+pub(crate) fn as_slink<ErrorVariant: ErrorLinkable<ErrorVariant, String>>(
+    error: ErrorVariant
+) -> ErrorLink_<String> {
+    let mut error = Some(error);
+    let error_any = &mut error as &mut dyn Any;
+    if let Some(error_link) = error_any.downcast_mut::<Option<ErrorLink_<String>>>() {
+        return error_link.take().expect("was just set");
+    }
+    if let Some(error_link) = error_any.downcast_mut::<Option<ErrorLink_<LinkMessage>>>() {
+        let error_link = error_link.take().expect("was just set");
+        return ErrorLink_(error_link.0.into_owned(), error_link.1, error_link.2);
+    }
+    error.expect("was just set").as_link()
+}
+
 impl<Payload: Display> Display for ErrorLink_<Payload> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "An error occurred.\n")?;
-        write!(f, "Link no. 0: {}\n", self.0)?;
-        let mut next_link = &self.1;
-        for error_number in 1.. {
-            next_link = match next_link {
-                NextLink::None(end_backtrace) => {
-                    write!(
-                        f, "Approximate backtrace of link no. {}:\n{end_backtrace}", 
-                        error_number - 1
-                    )?;
-                    break;
-                }, 
-                NextLink::Some(error_link) => {
-                    write!(f, "Link no. {error_number}: {}\n", error_link.0)?;
-                    &error_link.1
-                },
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return write_compact(f, &self.0, &self.1);
+        }
+        writeln!(f, "An error occurred.")?;
+        write_links(f, &self.0, &self.1, &self.2, 0)
+    }
+}
+
+/// Writes only the messages, on one line, e.g., "Higher level error.: Underlying error.".
+pub(crate) fn write_compact(f: &mut impl Write, payload: &dyn Display, next_link: &NextLink) -> core::fmt::Result {
+    write!(f, "{payload}")?;
+    match next_link {
+        NextLink::None(_) => Ok(()),
+        NextLink::Some(_) | NextLink::Shared(_) => {
+            let error_link = next_link.next_links().next().expect("has the one link");
+            write!(f, ": ")?;
+            write_compact(f, &error_link.0, &error_link.1)
+        },
+        NextLink::Many(error_links) => {
+            write!(f, ": [")?;
+            for (branch_index, error_link) in error_links.iter().enumerate() {
+                if branch_index > 0 {
+                    write!(f, "; ")?;
+                }
+                write_compact(f, &error_link.0, &error_link.1)?;
             }
+            write!(f, "]")
         }
+    }
+}
 
-        Ok(())
+pub(crate) fn write_links(
+    f: &mut impl core::fmt::Write,
+    payload: &dyn Display,
+    next_link: &NextLink,
+    attachments: &Attachments,
+    link_number: usize
+) -> core::fmt::Result {
+    writeln!(f, "Link no. {link_number}: {payload}")?;
+    let mut linear_links = vec![(link_number, attachments)];
+    let mut ends_with_newline = true;
+    let mut next_link = next_link;
+    for error_number in link_number + 1.. {
+        next_link = match next_link {
+            NextLink::None(end_capture) => {
+                let end_backtrace = render_capture(end_capture.as_capture());
+                write!(
+                    f, "Approximate backtrace of link no. {}:\n{end_backtrace}", 
+                    error_number - 1
+                )?;
+                ends_with_newline = end_backtrace.ends_with('\n');
+                break;
+            }, 
+            NextLink::Some(_) | NextLink::Shared(_) => {
+                let error_link = next_link.next_links().next().expect("has the one link");
+                writeln!(f, "Link no. {error_number}: {}", error_link.0)?;
+                linear_links.push((error_number, &error_link.2));
+                &error_link.1
+            },
+            NextLink::Many(error_links) => {
+                writeln!(f, "Caused by {} errors:", error_links.len())?;
+                write_branches(f, error_links, error_number)?;
+                break;
+            }
+        }
     }
+
+    let mut sections = String::new();
+    for (error_number, attachments) in &linear_links {
+        write_sections(&mut sections, *error_number, attachments)?;
+    }
+    for (error_number, attachments) in linear_links {
+        let suppressed = Suppressed::of(attachments);
+        if !suppressed.is_empty() {
+            writeln!(sections, "Suppressed while handling link no. {error_number}:")?;
+            write_branches(&mut sections, suppressed, 0)?;
+        }
+    }
+    if !sections.is_empty() && !ends_with_newline {
+        writeln!(f)?;
+    }
+    f.write_str(&sections)
+}
+
+fn write_sections(
+    f: &mut impl core::fmt::Write, link_number: usize, attachments: &Attachments
+) -> core::fmt::Result {
+    #[cfg(feature = "std")]
+    if let context_scopes @ [_, ..] = ContextScopes::of(attachments) {
+        writeln!(f, "Context scopes of link no. {link_number}:")?;
+        for (scope_number, message) in context_scopes.iter().enumerate() {
+            writeln!(f, "{scope_number:>4}: {message}")?;
+        }
+    }
+    #[cfg(feature = "codes")]
+    if let Some(error_code) = attachments.get::<ErrorCode>() {
+        writeln!(f, "Error code of link no. {link_number}: {error_code}")?;
+    }
+    let return_trace = ReturnTrace::of(attachments);
+    if !return_trace.is_empty() {
+        writeln!(f, "Return trace of link no. {link_number}:")?;
+        for (site_number, location) in return_trace.iter().enumerate() {
+            writeln!(f, "{site_number:>4}: {}", StableLocation(location))?;
+            if snippet_frames() > 0 && !is_deterministic() {
+                write_snippet(f, location.file(), location.line() as usize)?;
+            }
+        }
+    }
+    #[cfg(feature = "tracing")]
+    if let Some(span_trace) = attachments.get::<tracing_error::SpanTrace>() {
+        writeln!(f, "Span trace of link no. {link_number}:")?;
+        writeln!(f, "{}", render_span_trace(span_trace).trim_end())?;
+    }
+
+    Ok(())
 }
 
-impl<Payload: Display + Debug> std::error::Error for ErrorLink_<Payload> {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self.1 {
-            NextLink::None(_) => None,
-            NextLink::Some(next_link) => Some(next_link)
+fn write_branches(
+    f: &mut impl core::fmt::Write, error_links: &[ErrorLink_<LinkMessage>], link_number: usize
+) -> core::fmt::Result {
+    for (branch_index, error_link) in error_links.iter().enumerate() {
+        let mut branch = String::new();
+        write_links(&mut branch, &error_link.0, &error_link.1, &error_link.2, link_number)?;
+        let (first_prefix, rest_prefix) = if branch_index + 1 == error_links.len() {
+            ("└─ ", "   ")
+        } else {
+            ("├─ ", "│  ")
+        };
+        for (line_index, line) in branch.lines().enumerate() {
+            let prefix = if line_index == 0 { first_prefix } else { rest_prefix };
+            writeln!(f, "{prefix}{line}")?;
         }
     }
+
+    Ok(())
+}
+
+impl<Payload: Display + Debug> core::error::Error for ErrorLink_<Payload> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        // Only a single source can be exposed, so for `NextLink::Many` it is that of the first branch.
+        self.1
+            .next_links()
+            .as_slice()
+            .first()
+            .map(|next_link| next_link as &(dyn core::error::Error + 'static))
+    }
 }
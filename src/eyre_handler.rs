@@ -0,0 +1,62 @@
+use std::backtrace::Backtrace;
+use std::error::Error;
+use std::fmt::Display;
+use std::iter::successors;
+use crate::{Attachments, ErrorLink_, LinkMessage, NextLink};
+use crate::deterministic::render_capture;
+use crate::error_link_::{write_compact, write_links};
+
+/// Renders `eyre::Report`s as `ErrorLink_`s are rendered. Any `ErrorLink_<String>` or
+/// `ErrorLink_<LinkMessage>` in the chain of a report is rendered as is, including its own
+/// backtrace.
+pub struct LinkHandler(Backtrace);
+
+/// Makes `LinkHandler` the handler of all subsequently created `eyre::Report`s.
+pub fn install_eyre_hook() -> Result<(), eyre::InstallError> {
+    eyre::set_hook(Box::new(|_| Box::new(LinkHandler(Backtrace::capture()))))
+}
+
+impl eyre::EyreHandler for LinkHandler {
+    fn debug(&self, error: &(dyn Error + 'static), f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "An error occurred.")?;
+        let mut last_number = 0;
+        for (link_number, error) in successors(Some(error), |&error| error.source()).enumerate() {
+            if let Some((payload, next_link, attachments)) = as_link_parts(error) {
+                return write_links(f, payload, next_link, attachments, link_number);
+            }
+            writeln!(f, "Link no. {link_number}: {error}")?;
+            last_number = link_number;
+        }
+        write!(f, "Approximate backtrace of link no. {last_number}:\n{}", render_capture(&self.0))
+    }
+
+    fn display(&self, error: &(dyn Error + 'static), f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (link_number, error) in successors(Some(error), |&error| error.source()).enumerate() {
+            if link_number > 0 {
+                if !f.alternate() {
+                    break;
+                }
+                write!(f, ": ")?;
+            }
+            match as_link_parts(error) {
+                Some((payload, next_link, _)) if f.alternate() => return write_compact(f, payload, next_link),
+                Some((payload, _, _)) => return write!(f, "{payload}"),
+                None => write!(f, "{error}")?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The parts of the `ErrorLink_` an error of a report is, for it to be rendered as is.
+fn as_link_parts<'a>(
+    error: &'a (dyn Error + 'static)
+) -> Option<(&'a dyn Display, &'a NextLink, &'a Attachments)> {
+    if let Some(error_link) = error.downcast_ref::<ErrorLink_<String>>() {
+        return Some((&error_link.0, &error_link.1, &error_link.2));
+    }
+    error
+        .downcast_ref::<ErrorLink_<LinkMessage>>()
+        .map(|error_link| (&error_link.0 as &dyn Display, &error_link.1, &error_link.2))
+}
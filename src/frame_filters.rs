@@ -0,0 +1,52 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+use crate::Frame;
+
+#[cfg(feature = "std")]
+type FrameFilter = Box<dyn Fn(&Frame) -> bool + Send + Sync>;
+
+#[cfg(feature = "std")]
+pub(crate) static FRAME_FILTERS: RwLock<Vec<FrameFilter>> = RwLock::new(Vec::new());
+static DEFAULT_FRAME_FILTERS: AtomicBool = AtomicBool::new(true);
+
+/// Hides, from rendered backtraces, the frames that `hides` returns true for, e.g.,
+/// `|frame| frame.symbol.starts_with("tokio::")`.
+#[cfg(feature = "std")]
+pub fn add_frame_filter(hides: impl Fn(&Frame) -> bool + Send + Sync + 'static) {
+    FRAME_FILTERS.write().unwrap_or_else(|e| e.into_inner()).push(Box::new(hides));
+}
+
+/// Whether the frames of `std`, `core`, `alloc`, the test runtime and this crate itself are
+/// hidden, which they are by default.
+pub fn set_default_frame_filters(enabled: bool) {
+    DEFAULT_FRAME_FILTERS.store(enabled, Ordering::Relaxed);
+}
+
+fn is_runtime_frame(symbol: &str) -> bool {
+    const RUNTIME_PREFIXES: [&str; 11] = [
+        "std::", "core::", "alloc::", "test::", "<std::", "<core::", "<alloc::",
+        "__rust_", "__libc_start", "_start", "rust_begin_unwind"
+    ];
+    RUNTIME_PREFIXES.iter().any(|prefix| symbol.starts_with(prefix))
+        || symbol.contains(" as core::ops::function::Fn")
+        || symbol == "<unknown>"
+}
+
+/// E.g., "micro_errors::ErrorLink_<P>::new", or "<micro_errors::ErrorLink_<P> as ..>::fmt", but
+/// neither a module of another crate named `micro_errors` nor a crate named `micro_errors_*`.
+fn is_own_frame(symbol: &str) -> bool {
+    symbol.strip_prefix('<').unwrap_or(symbol).starts_with("micro_errors::")
+}
+
+pub(crate) fn is_hidden(frame: &Frame) -> bool {
+    let symbol = frame.symbol.as_str();
+    if DEFAULT_FRAME_FILTERS.load(Ordering::Relaxed) && (is_runtime_frame(symbol) || is_own_frame(symbol)) {
+        return true;
+    }
+    #[cfg(feature = "std")]
+    if FRAME_FILTERS.read().unwrap_or_else(|e| e.into_inner()).iter().any(|hides| hides(frame)) {
+        return true;
+    }
+    false
+}
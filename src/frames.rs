@@ -0,0 +1,157 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Write};
+use crate::{Capture, ErrorLink_};
+use crate::frame_filters::is_hidden;
+use crate::source_snippets::write_snippet;
+
+/// A frame of a backtrace, i.e., a symbol, and where it is if known. Each inlined symbol is a
+/// frame of its own.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Frame {
+    /// The number of the frame as captured, which the frames of inlined symbols share with the
+    /// frame they were inlined into.
+    pub number: usize,
+    pub symbol: String,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub column: Option<u32>
+}
+
+impl Frame {
+    /// Parses the frames out of the `Display` of a `std::backtrace::Backtrace`, as it cannot be
+    /// iterated over on stable.
+    pub fn parse_all(backtrace: &str) -> Vec<Frame> {
+        let mut frames = Vec::<Frame>::new();
+        for line in backtrace.lines() {
+            let trimmed_line = line.trim_start();
+            if let Some(location) = trimmed_line.strip_prefix("at ") {
+                if let Some(frame) = frames.last_mut().filter(|frame| frame.file.is_none()) {
+                    frame.set_location(location);
+                }
+                continue;
+            }
+            let numbered_symbol = trimmed_line
+                .split_once(": ")
+                .filter(|(frame_number, _)| frame_number.bytes().all(|byte| byte.is_ascii_digit()))
+                .and_then(|(frame_number, symbol)| Some((frame_number.parse().ok()?, symbol.trim_start())));
+            let (number, symbol) = match (numbered_symbol, frames.last()) {
+                (Some(numbered_symbol), _) => numbered_symbol,
+                // An inlined symbol, which is on a line of its own without a number.
+                (None, Some(frame)) if trimmed_line.len() < line.len() && !trimmed_line.is_empty() =>
+                    (frame.number, trimmed_line),
+                _ => continue
+            };
+            // When `RUST_BACKTRACE=full`, symbols are preceded by their address, e.g., "0x55d1c0de - ".
+            let symbol = match symbol.split_once(" - ") {
+                Some((address, symbol)) if address.starts_with("0x") => symbol,
+                _ => symbol
+            };
+            frames.push(Frame { number, symbol: symbol.to_string(), file: None, line: None, column: None });
+        }
+        frames
+    }
+
+    /// Takes the frames of a `backtrace::Backtrace`, which must have been resolved to have symbols.
+    #[cfg(feature = "backtrace")]
+    pub fn resolve_all(backtrace: &backtrace::Backtrace) -> Vec<Frame> {
+        let mut frames = Vec::new();
+        for (number, frame) in backtrace.frames().iter().enumerate() {
+            if frame.symbols().is_empty() {
+                frames.push(Frame { number, symbol: String::from("<unknown>"), file: None, line: None, column: None });
+            }
+            for symbol in frame.symbols() {
+                frames.push(Frame {
+                    number,
+                    symbol: symbol.name().map_or_else(|| String::from("<unknown>"), |name| name.to_string()),
+                    file: symbol.filename().map(|file| file.display().to_string()),
+                    line: symbol.lineno(),
+                    column: symbol.colno()
+                });
+            }
+        }
+        frames
+    }
+
+    /// Parses, e.g., "./src/lib.rs:254:59", where the column, or the line too, may be missing.
+    fn set_location(&mut self, location: &str) {
+        let mut location_parts = location.rsplitn(3, ':');
+        let column = location_parts.next().and_then(|column| column.parse().ok());
+        let line = location_parts.next().and_then(|line| line.parse().ok());
+        match (location_parts.next(), line, column) {
+            (Some(file), Some(line), Some(column)) => {
+                (self.file, self.line, self.column) = (Some(file.to_string()), Some(line), Some(column));
+            },
+            _ => match location.rsplit_once(':') {
+                Some((file, line)) if line.parse::<u32>().is_ok() => {
+                    (self.file, self.line) = (Some(file.to_string()), line.parse().ok());
+                },
+                _ => self.file = Some(location.to_string())
+            }
+        }
+    }
+}
+
+/// Writes as `std::backtrace::Backtrace` does, e.g., "main\n             at ./src/main.rs:7:26".
+impl Display for Frame {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.symbol)?;
+        if let Some(file) = &self.file {
+            write!(f, "\n             at {file}")?;
+            if let Some(line) = self.line {
+                write!(f, ":{line}")?;
+            }
+            if let Some(column) = self.column {
+                write!(f, ":{column}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Payload: Display> ErrorLink_<Payload> {
+    /// The frames of `end_capture`, being empty when there are none, e.g., as not captured.
+    pub fn backtrace_frames(&self) -> Vec<Frame> {
+        self.end_capture().and_then(Capture::frames).unwrap_or_default()
+    }
+}
+
+/// Writes the frames numbered as they were captured, except for the hidden ones, each run of which
+/// becomes a "[N frames hidden]" marker. As with a std `Backtrace`, inlined symbols are written
+/// without the number they share. The top `snippet_frames` frames with sources on disk get
+/// snippets.
+pub(crate) fn render_frames(frames: &[Frame], snippet_frames: usize) -> String {
+    let mut rendered = String::new();
+    let mut hidden_frames = 0;
+    let mut snippets_left = snippet_frames;
+    let mut previous_number = None;
+    for frame in frames {
+        let is_inlined = previous_number.replace(frame.number) == Some(frame.number);
+        if is_hidden(frame) {
+            hidden_frames += 1;
+            continue;
+        }
+        write_marker(&mut rendered, core::mem::take(&mut hidden_frames));
+        let _ = match is_inlined {
+            true => writeln!(rendered, "      {frame}"),
+            false => writeln!(rendered, "{:>4}: {frame}", frame.number)
+        };
+        if let (Some(file), Some(line), 1..) = (&frame.file, frame.line, snippets_left) {
+            let snippet_start = rendered.len();
+            let _ = write_snippet(&mut rendered, file, line as usize);
+            if rendered.len() > snippet_start {
+                snippets_left -= 1;
+            }
+        }
+    }
+    write_marker(&mut rendered, hidden_frames);
+    rendered
+}
+
+fn write_marker(rendered: &mut String, hidden_frames: usize) {
+    if hidden_frames > 0 {
+        let plural = if hidden_frames == 1 { "" } else { "s" };
+        let _ = writeln!(rendered, "      [{hidden_frames} frame{plural} hidden]");
+    }
+}
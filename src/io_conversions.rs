@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::io;
+use crate::ErrorLink_;
+
+// `Box<dyn Error + Send + Sync>` is already covered by `std`'s `From` for all `Error`s, which
+// keeps the `ErrorLink_` inside too, so only the way back out is provided for it.
+impl<Payload: Display + Debug + Send + Sync + 'static> ErrorLink_<Payload> {
+    /// Keeps the `ErrorLink_` as the inner error, for `from_io_error` to later recover.
+    pub fn into_io_error(self, kind: io::ErrorKind) -> io::Error {
+        io::Error::new(kind, self)
+    }
+
+    /// Recovers the `ErrorLink_` put inside by `into_io_error`, otherwise gives back `io_error`.
+    pub fn from_io_error(io_error: io::Error) -> Result<Self, io::Error> {
+        if !io_error.get_ref().is_some_and(|inner_error| inner_error.is::<Self>()) {
+            return Err(io_error);
+        }
+        let inner_error = io_error.into_inner().expect("was just checked");
+        Ok(*inner_error.downcast::<Self>().expect("was just checked"))
+    }
+
+    /// Recovers the `ErrorLink_` put inside by `Into`/`?`, otherwise gives back `boxed_error`.
+    pub fn from_boxed_error(
+        boxed_error: Box<dyn Error + Send + Sync>
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        boxed_error.downcast::<Self>().map(|error_link| *error_link)
+    }
+}
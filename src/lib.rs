@@ -1,30 +1,128 @@
-#![cfg_attr(feature = "nightly", feature(min_specialization))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly", feature(min_specialization, try_trait_v2, try_trait_v2_residual))]
+#![cfg_attr(all(feature = "nightly", feature = "anyhow"), feature(error_generic_member_access))]
 
+extern crate alloc;
+#[doc(hidden)]
+pub extern crate alloc as __alloc;
+
+#[cfg(feature = "anyhow")]
+mod anyhow_conversions;
+mod assertions;
+mod attachments;
+mod capture;
+#[cfg(feature = "std")]
+mod context_scope;
+mod deterministic;
+#[cfg(feature = "legacy")]
 mod error_chain;
+#[cfg(feature = "codes")]
+mod error_codes;
 mod error_link_;
+#[cfg(feature = "eyre")]
+mod eyre_handler;
+mod frame_filters;
+mod frames;
+#[cfg(feature = "std")]
+mod io_conversions;
+mod linkable_future;
+mod linkable_iterator;
 mod linkable_results;
+#[cfg(feature = "log")]
+mod log_records;
+#[cfg(feature = "futures")]
+mod linkable_stream;
+#[cfg(feature = "symbolize")]
+mod raw_backtrace;
 #[cfg(feature = "nightly")]
 mod result_ext;
+mod return_trace;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shared_link;
+mod source_snippets;
+mod static_link;
+mod suppressed;
+#[cfg(feature = "tracing")]
+mod tracing_events;
 
+pub use assertions::RootCause;
+#[doc(hidden)]
+pub use assertions::{check_chain, check_link_contains, check_root_cause};
+pub use attachments::Attachments;
+#[cfg(feature = "backtrace")]
+pub use capture::DeferredBacktrace;
+#[cfg(feature = "std")]
+pub use capture::set_capture;
+pub use capture::{Capture, EndCapture, NoCapture};
+#[cfg(feature = "std")]
+pub use context_scope::{context_scope, ContextScope};
+pub use deterministic::{is_deterministic, set_deterministic};
+#[cfg(feature = "codes")]
+pub use error_codes::{explain, explanations, ErrorCode, Explanation, HasErrorCode};
+#[cfg(all(feature = "codes", feature = "std"))]
+pub use error_codes::explain_main;
+#[cfg(feature = "codes")]
+#[doc(hidden)]
+pub use error_codes::ERROR_CODES;
+#[cfg(feature = "codes")]
+#[doc(hidden)]
+pub use linkme as __linkme;
+#[cfg(feature = "insta")]
+#[doc(hidden)]
+pub use insta as __insta;
+#[cfg(feature = "legacy")]
 #[allow(deprecated)]
 pub use error_chain::{ErrorLink, ErrorChain};
-pub use linkable_results::{LinkableResult1of2, LinkableResult2of2};
+pub use linkable_future::FutureLinkable;
+pub use linkable_iterator::LinkableIterator;
+pub use linkable_results::{LinkableResult1of2, LinkableResult2of2, TraceableResult};
+#[cfg(feature = "futures")]
+pub use linkable_stream::StreamLinkable;
+#[cfg(feature = "log")]
+pub use log_records::LoggableResult;
+#[cfg(feature = "symbolize")]
+pub use raw_backtrace::RawBacktrace;
 #[cfg(feature = "nightly")]
 pub use result_ext::ResultExt;
-pub use error_link_::{ErrorLink_, NextLink, ErrorLinkable};
+#[cfg(feature = "nightly")]
+pub use return_trace::Traced;
+pub use error_link_::{ErrorLink_, NextLink, ErrorLinkable, LinkMessage};
+#[cfg(feature = "eyre")]
+pub use eyre_handler::{install_eyre_hook, LinkHandler};
+#[cfg(feature = "std")]
+pub use frame_filters::add_frame_filter;
+pub use frame_filters::set_default_frame_filters;
+pub use frames::Frame;
+pub use shared_link::SharedLink;
+pub use source_snippets::set_source_snippets;
+pub use static_link::StaticLink;
+pub use suppressed::{defer, Deferred};
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "legacy")]
     #[allow(deprecated)]
     use crate::ErrorChain;
     use crate::ErrorLink_;
     use crate::ErrorLinkable;
     use crate::NextLink;
+    use crate::Attachments;
     #[cfg(feature = "nightly")]
     use crate::ResultExt;
-    use std::backtrace::Backtrace;
+    #[cfg(feature = "nightly")]
+    use crate::Traced;
+    use crate::EndCapture;
     use crate::LinkableResult1of2;
     use crate::LinkableResult2of2;
+    use crate::TraceableResult;
+    use crate::LinkableIterator;
+    #[cfg(feature = "std")]
+    use crate::FutureLinkable;
+    #[cfg(feature = "futures")]
+    use crate::StreamLinkable;
+    #[cfg(feature = "log")]
+    use crate::LoggableResult;
 
     #[allow(dead_code)]
     trait X {
@@ -68,6 +166,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "legacy")]
     #[allow(non_snake_case)]
     #[allow(deprecated)]
     fn test__chaining_non_error_chain() {
@@ -95,6 +194,194 @@ mod tests {
     }
 
     #[test]
+    #[allow(non_snake_case)]
+    fn test__comparing_and_hashing() {
+        let new_link = || ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error.");
+        assert_eq!(new_link(), new_link());
+        assert_ne!(new_link(), ErrorLink_::new_string("Higher level error."));
+        assert_ne!(new_link(), new_link().link("Highest level error."));
+        assert_ne!(
+            ErrorLink_::<String>::new_many("Higher level error.", [new_link()]),
+            ErrorLink_::new_many("Higher level error.", [new_link(), new_link()])
+        );
+        // The backtrace, lazily resolved, is mutable inside but is neither compared nor hashed.
+        #[allow(clippy::mutable_key_type)]
+        let error_links = std::collections::HashSet::from([
+            new_link(), new_link(), ErrorLink_::new_string("Higher level error.").attach(42)
+        ]);
+        assert_eq!(error_links.len(), 2);
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__sharing() {
+        let shared_link = ErrorLink_::new_string("Underlying error.").shared();
+        let waiters = [shared_link.clone(), shared_link.clone()];
+        for waiter in &waiters {
+            let format_output = format!("{}", waiter.clone().link::<String>("Higher level error."));
+            println!("{}", format_output);
+            is_output_default(&format_output);
+        }
+        assert_eq!(
+            format!("{:#}", waiters[0].clone().link::<String>("Higher level error.")),
+            "Higher level error.: Underlying error."
+        );
+        assert_eq!(
+            waiters[0].clone().link::<String>("A."),
+            ErrorLink_::new_string("Underlying error.").link("A.")
+        );
+        drop(waiters);
+        let error_link = shared_link.try_unwrap().expect("look above");
+        assert_eq!(error_link.0, "Underlying error.");
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__link_messages() {
+        use std::borrow::Cow;
+        let error_link = ErrorLink_::new_message("Underlying error.").link::<crate::LinkMessage>("Higher level error.");
+        is_output_default(&format!("{error_link}"));
+        assert!(matches!(error_link.0, Cow::Borrowed("Higher level error.")));
+        assert!(error_link.links().all(|error_link| matches!(error_link.0, Cow::Borrowed(_))));
+
+        let error_message = String::from("Underlying error.");
+        let message_pointer = error_message.as_ptr();
+        let error_link = ErrorLink_::new_string(error_message).link::<String>("Higher level error.");
+        assert_eq!(error_link.links().next().expect("look above").0.as_ptr(), message_pointer);
+        let error_link = ErrorLink_::<&str>::new("Underlying error.").link::<String>("Higher level error.");
+        assert!(matches!(error_link.links().next().expect("look above").0, Cow::Borrowed(_)));
+
+        let error_message = String::from("Underlying error.");
+        let message_pointer = error_message.as_ptr();
+        let error_link = Err::<(), _>(ErrorLink_::new_string(error_message))
+            .me_link::<String>("Higher level error.")
+            .expect_err("look above");
+        assert_eq!(error_link.links().next().expect("look above").0.as_ptr(), message_pointer);
+        let error_link = Err::<(), _>(ErrorLink_::<&str>::new("Underlying error."))
+            .me_link::<String>("Higher level error.")
+            .expect_err("look above");
+        assert!(matches!(error_link.links().next().expect("look above").0, Cow::Borrowed(_)));
+    }
+
+    #[cfg(feature = "codes")]
+    crate::register_error_code!(POOL_EXHAUSTED = crate::Explanation {
+        code: crate::ErrorCode("E-DB-0042"),
+        summary: "The database connection pool is exhausted.",
+        details: "Every connection was in use for longer than the acquire timeout.",
+        doc_url: Some("https://docs.example.com/errors/E-DB-0042")
+    });
+
+    #[test]
+    #[cfg(feature = "codes")]
+    #[allow(non_snake_case)]
+    fn test__error_codes() {
+        enum DatabaseError {
+            PoolExhausted
+        }
+        impl std::fmt::Display for DatabaseError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Underlying error.")
+            }
+        }
+        impl crate::HasErrorCode for DatabaseError {
+            fn error_code(&self) -> crate::ErrorCode {
+                match self {
+                    DatabaseError::PoolExhausted => crate::ErrorCode("E-DB-0042")
+                }
+            }
+        }
+
+        let error_link = ErrorLink_::<DatabaseError>::new(DatabaseError::PoolExhausted)
+            .coded()
+            .link::<String>("Higher level error.");
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        is_output_default(&format_output);
+        assert!(format_output.contains("Error code of link no. 1: E-DB-0042\n"));
+        let error_code = error_link.error_code().expect("look above");
+        assert_eq!(error_code, crate::ErrorCode("E-DB-0042"));
+        assert!(ErrorLink_::new_string("Another error.").error_code().is_none());
+
+        let explanation = error_code.explanation().expect("look above");
+        assert_eq!(
+            explanation.to_string(),
+            "E-DB-0042: The database connection pool is exhausted.\n\n\
+            Every connection was in use for longer than the acquire timeout.\n\n\
+            See https://docs.example.com/errors/E-DB-0042\n"
+        );
+        assert!(crate::explain(" E-DB-0042\n").is_some());
+        assert!(crate::explain("E-DB-0043").is_none());
+        assert!(crate::explanations().iter().any(|explanation| explanation.code == error_code));
+        #[cfg(feature = "std")]
+        {
+            crate::explain_main!();
+            let _: fn() -> std::process::ExitCode = main;
+        }
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__static_links() {
+        const OUT_OF_MEMORY: crate::StaticLink<3> = crate::StaticLink::new("Underlying error.")
+            .link("Higher level error.");
+        let error_link = ErrorLink_::<String>::new_capturing::<crate::NoCapture>("Underlying error.")
+            .link::<String>("Higher level error.");
+        assert_eq!(format!("{OUT_OF_MEMORY}"), format!("{error_link}"));
+        assert_eq!(format!("{OUT_OF_MEMORY:#}"), "Higher level error.: Underlying error.");
+        is_output_default(&format!("{OUT_OF_MEMORY}"));
+        assert_eq!(ErrorLink_::<String>::from(OUT_OF_MEMORY), error_link);
+        assert_eq!(format!("{}", ErrorLink_::<String>::from(OUT_OF_MEMORY)), format!("{error_link}"));
+
+        let static_link = OUT_OF_MEMORY.link("A.");
+        assert!(static_link.is_full());
+        let static_link = Err::<(), _>(static_link)
+            .map_err(crate::StaticLink::link_fn("B."))
+            .expect_err("look above");
+        assert_eq!(static_link.messages().collect::<Vec<_>>(), ["B.", "A.", "Underlying error."]);
+        assert_eq!(static_link.dropped(), 1);
+        assert!(format!("{static_link}").contains(
+            "Link no. 0: B.\nLink no. 1: A.\nLink no. 2: [1 link omitted]\nLink no. 3: Underlying error.\n\
+            Approximate backtrace of link no. 3:\n"
+        ));
+        assert_eq!(format!("{static_link:#}"), "B.: A.: [1 link omitted]: Underlying error.");
+        let error_link = ErrorLink_::<String>::from(static_link.link("C."));
+        assert_eq!(format!("{error_link:#}"), "C.: B.: [2 links omitted]: Underlying error.");
+        assert_eq!(format!("{error_link}"), format!("{}", static_link.link("C.")));
+
+        let static_link = crate::StaticLink::<1>::new("Root.").link("A.");
+        assert!(static_link.is_full());
+        assert_eq!(static_link.messages().collect::<Vec<_>>(), ["Root."]);
+        assert_eq!(static_link.dropped(), 1);
+        assert_eq!(format!("{static_link:#}"), "[1 link omitted]: Root.");
+        let error_link = ErrorLink_::<String>::from(static_link.link("B."));
+        assert_eq!(format!("{error_link:#}"), "[2 links omitted]: Root.");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[allow(non_snake_case)]
+    fn test__asserting_chain() {
+        let error_link = std::io::Error::new(std::io::ErrorKind::NotFound, "Underlying error.")
+            .link("Higher level error.");
+        crate::assert_chain!(error_link, ["Higher level error.", "Underlying error."]);
+        crate::assert_root_cause!(error_link, std::io::ErrorKind::NotFound);
+        crate::assert_root_cause!(error_link, "Underlying error.");
+        crate::assert_link_contains!(error_link, "Underlying");
+
+        let chain_diff = crate::check_chain(
+            &error_link, &[String::from("Higher level error."), String::from("Other error.")]
+        ).expect_err("look above");
+        assert_eq!(
+            chain_diff,
+            "Link messages differ (- expected, + actual):\n  link no. 0: \"Higher level error.\"\n\
+            - link no. 1: \"Other error.\"\n+ link no. 1: \"Underlying error.\"\n"
+        );
+        assert!(crate::check_root_cause(&error_link, &std::io::ErrorKind::Other).is_err());
+        assert!(crate::check_link_contains(&error_link, "Other").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "legacy")]
     #[allow(deprecated)]
     #[allow(non_snake_case)]
     fn test__chaining_error_chain() {
@@ -108,6 +395,22 @@ mod tests {
         is_output_default(&format_output);
     }
 
+    #[test]
+    #[cfg(feature = "legacy")]
+    #[allow(deprecated)]
+    #[allow(non_snake_case)]
+    fn test__migrating_error_chain() {
+        let error_chain = ErrorChain::link("Higher level error.", ErrorChain::start("Underlying error."));
+        let error_link: ErrorLink_<String> = error_chain.into();
+        assert_eq!(error_link.0, "Higher level error.");
+        let NextLink::Some(next_link) = &error_link.1 else { panic!("look above") };
+        assert_eq!(next_link.0, "Underlying error.");
+        assert!(matches!(next_link.1, NextLink::None(_)));
+        let format_output = format!("{}", error_link);
+        println!("{}", format_output);
+        is_output_default(&format_output);
+    }
+
     #[test]
     #[allow(non_snake_case)]
     fn test__chaining_error_link_() {
@@ -122,7 +425,7 @@ mod tests {
 
     impl ErrorLink_<i32> {
         pub fn new_i32(error_number: impl Into<i32>) -> Self {
-            Self(error_number.into(), NextLink::None(Backtrace::capture()))
+            Self(error_number.into(), NextLink::None(EndCapture::capture()), Attachments::new())
         }
     }
     #[derive(Debug, PartialEq)]
@@ -134,7 +437,7 @@ mod tests {
     }
     impl ErrorLink_<ErrorReasons> {
         pub fn new_reason(error_reason: ErrorReasons) -> Self {
-            Self(error_reason, NextLink::None(Backtrace::capture()))
+            Self(error_reason, NextLink::None(EndCapture::capture()), Attachments::new())
         }
     }
     impl std::fmt::Display for ErrorReasons {
@@ -198,6 +501,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "legacy")]
     #[allow(deprecated)]
     #[allow(non_snake_case)]
     fn test__chaining_non_error_chain_and_non_error_trait() {
@@ -408,4 +712,786 @@ mod tests {
             Ok(())
         }();
     }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    #[allow(non_snake_case)]
+    fn test__io_error_kind__through_me_link() {
+        let not_found = || Err::<(), _>(std::io::Error::from(std::io::ErrorKind::NotFound));
+        let error_link = not_found().me_link::<String>("Higher level error.").expect_err("look above");
+        assert_eq!(error_link.request_ref::<std::io::ErrorKind>(), Some(&std::io::ErrorKind::NotFound));
+        let error_link = not_found().me_as_slink().expect_err("look above");
+        assert_eq!(error_link.request_ref::<std::io::ErrorKind>(), Some(&std::io::ErrorKind::NotFound));
+        let error_link = not_found().me_l("Higher level error.").expect_err("look above");
+        assert_eq!(error_link.request_ref::<std::io::ErrorKind>(), Some(&std::io::ErrorKind::NotFound));
+        let error_link = not_found().me_al().expect_err("look above");
+        assert_eq!(error_link.request_ref::<std::io::ErrorKind>(), Some(&std::io::ErrorKind::NotFound));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct RequestId(u64);
+    #[derive(Debug, PartialEq)]
+    struct RetryAfter(std::time::Duration);
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__attachments__requested_from_any_link() {
+        let error_link: ErrorLink_<String> = Err::<(), _>(ErrorLink_::new_string("Underlying error."))
+            .map_err(|e| e.attach(RequestId(42)))
+            .map_err(|e| e.link::<String>("Middle level error."))
+            .map_err(|e| e.attach(RetryAfter(std::time::Duration::from_secs(1))))
+            .me_link::<String>("Higher level error.")
+            .expect_err("look above");
+        assert_eq!(error_link.request_ref::<RequestId>(), Some(&RequestId(42)));
+        assert_eq!(
+            error_link.request_ref::<RetryAfter>(), 
+            Some(&RetryAfter(std::time::Duration::from_secs(1)))
+        );
+        assert_eq!(error_link.request_ref::<u8>(), None);
+
+        let error_link = error_link.attach(RequestId(7));
+        assert_eq!(error_link.request_ref::<RequestId>(), Some(&RequestId(7)));
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__many_links__tree_rendering() {
+        let error_link: ErrorLink_<String> = ErrorLink_::new_many(
+            "Higher level error.",
+            vec![
+                ErrorLink_::new_string("First underlying error.").link("First middle level error."),
+                ErrorLink_::new_string("Second underlying error."),
+            ]
+        );
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        assert!(format_output.contains(
+            "Link no. 0: Higher level error.\n\
+            Caused by 2 errors:\n\
+            ├─ Link no. 1: First middle level error.\n\
+            │  Link no. 2: First underlying error.\n\
+            │  Approximate backtrace of link no. 2:\n"
+        ));
+        assert!(format_output.contains(
+            "└─ Link no. 1: Second underlying error.\n   Approximate backtrace of link no. 1:\n"
+        ));
+        assert_eq!(
+            error_link.links().map(|error_link| error_link.0.as_ref()).collect::<Vec<_>>(),
+            ["First middle level error.", "First underlying error.", "Second underlying error."]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__many_links__serialization() {
+        let error_link: ErrorLink_<String> = ErrorLink_::new_many(
+            "Higher level error.",
+            vec![ErrorLink_::new_i32(1), ErrorLink_::new_i32(2)]
+        );
+        let mut serialized = serde_json::to_value(&error_link).expect("look above");
+        for cause in serialized["causes"].as_array_mut().expect("look above") {
+            cause["backtrace"] = serde_json::Value::Null;
+        }
+        assert_eq!(
+            serialized,
+            serde_json::json!({
+                "message": "Higher level error.",
+                "causes": [
+                    { "message": "1", "causes": [], "backtrace": null, "capture": null },
+                    { "message": "2", "causes": [], "backtrace": null, "capture": null },
+                ],
+                "backtrace": null,
+                "capture": null
+            })
+        );
+
+        struct OneFrame;
+        impl std::fmt::Display for OneFrame {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "   3: user_crate::function")
+            }
+        }
+        impl crate::Capture for OneFrame {
+            fn capture() -> Self {
+                OneFrame
+            }
+            fn frames(&self) -> Option<Vec<crate::Frame>> {
+                Some(crate::Frame::parse_all(&self.to_string()))
+            }
+        }
+        let error_link = ErrorLink_::<String>::new_capturing::<OneFrame>("Underlying error.");
+        assert_eq!(
+            serde_json::to_value(&error_link).expect("look above")["backtrace"],
+            serde_json::json!([
+                { "number": 3, "symbol": "user_crate::function", "file": null, "line": null, "column": null }
+            ])
+        );
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__collecting_all_errors() {
+        let results = ["1", "two", "3", "four"].into_iter().map(|number| number.parse::<i32>());
+        let error_link = results.clone().collect_all_errors().expect_err("look above");
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        assert!(format_output.contains("Link no. 0: 2 of 4 results were errors.\nCaused by 2 errors:\n"));
+        assert_eq!(error_link.links().count(), 2);
+
+        let (oks, error_links) = results.partition_links();
+        assert_eq!(oks, [1, 3]);
+        assert_eq!(error_links.len(), 2);
+
+        let error_link = [Ok(1), Err(ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error."))]
+            .into_iter()
+            .collect_all_errors()
+            .expect_err("look above");
+        assert_eq!(
+            error_link.links().map(|error_link| error_link.0.as_ref()).collect::<Vec<_>>(),
+            ["Higher level error.", "Underlying error."]
+        );
+
+        assert_eq!(
+            [Ok::<_, std::io::Error>(1), Ok(2)].into_iter().collect_all_errors().expect("look above"),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__suppressing_errors() {
+        let rollback = crate::defer(|| Err::<(), _>(std::io::Error::other("Rollback error.")));
+        let error_link = rollback
+            .finish(Err::<(), _>(ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error.")))
+            .expect_err("look above");
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        assert!(format_output.contains("Link no. 0: Higher level error.\nLink no. 1: Underlying error.\n"));
+        assert_eq!(error_link.suppressed().len(), 1);
+        assert!(format_output.contains(
+            "Suppressed while handling link no. 0:\n└─ Link no. 0: Rollback error.\n   Approximate backtrace"
+        ));
+
+        let removal = crate::defer(|| Err::<(), _>(ErrorLink_::new_string("Removal error.")));
+        let error_link = removal.finish(Ok::<_, ErrorLink_<String>>(())).expect_err("look above");
+        assert_eq!(error_link.0, "Removal error.");
+        assert!(error_link.suppressed().is_empty());
+
+        let unlocking = crate::defer(|| Err::<(), _>(ErrorLink_::new_string("Unlock error.")));
+        drop(unlocking);
+
+        let error_link = ErrorLink_::new_string("Underlying error.")
+            .suppress(ErrorLink_::new_i32(1))
+            .link::<String>("Higher level error.")
+            .suppress(ErrorLink_::new_i32(2))
+            .suppress(ErrorLink_::new_i32(3));
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        assert!(format_output.contains(
+            "Suppressed while handling link no. 0:\n├─ Link no. 0: 2\n"
+        ));
+        assert!(format_output.contains(
+            "Suppressed while handling link no. 1:\n└─ Link no. 0: 1\n"
+        ));
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__return_trace__explicit() {
+        fn underlying() -> Result<(), ErrorLink_<String>> {
+            Err(ErrorLink_::new_string("Underlying error."))
+        }
+        fn higher() -> Result<(), ErrorLink_<String>> {
+            underlying().me_trace()?;
+            Ok(())
+        }
+        let error_link = higher().me_trace().expect_err("look above");
+        assert_eq!(
+            error_link.return_trace().iter().map(|location| location.file()).collect::<Vec<_>>(),
+            ["src/lib.rs", "src/lib.rs"]
+        );
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        assert!(format_output.contains(&format!(
+            "Return trace of link no. 0:\n   0: {}\n   1: {}\n",
+            error_link.return_trace()[0], 
+            error_link.return_trace()[1]
+        )));
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__capturing_other() {
+        struct TaskId(u32);
+        impl std::fmt::Display for TaskId {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "In task {}.", self.0)
+            }
+        }
+        impl crate::Capture for TaskId {
+            fn capture() -> Self {
+                TaskId(7)
+            }
+        }
+
+        let error_link = ErrorLink_::<String>::new_capturing::<TaskId>("Underlying error.")
+            .link::<String>("Higher level error.");
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        assert!(format_output.ends_with("Approximate backtrace of link no. 1:\nIn task 7."));
+        is_output_default(&format_output);
+        assert!(error_link.backtrace_frames().is_empty());
+
+        let error_link = ErrorLink_::<String>::new_capturing::<crate::NoCapture>("Underlying error.");
+        assert_eq!(error_link.end_capture().map(ToString::to_string).as_deref(), Some("disabled backtrace"));
+        #[cfg(feature = "std")]
+        assert!(EndCapture::new(std::backtrace::Backtrace::disabled()).backtrace().is_some());
+        #[cfg(feature = "backtrace")]
+        {
+            let error_link = ErrorLink_::<String>::new_capturing::<crate::DeferredBacktrace>("Underlying error.");
+            assert!(error_link.backtrace_frames().iter().any(|frame| frame.symbol.contains("test__capturing_other")));
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "std"))]
+    #[allow(non_snake_case)]
+    fn test__capturing_without_std() {
+        let error_link = ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error.");
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        assert!(format_output.ends_with("Approximate backtrace of link no. 1:\ndisabled backtrace"));
+        is_output_default(&format_output);
+        assert!(matches!(
+            error_link.links().last().map(|error_link| &error_link.1),
+            Some(NextLink::None(end_capture)) if !end_capture.as_capture().is_captured()
+        ));
+        assert!(error_link.end_capture().is_some_and(|capture| !capture.is_captured()));
+    }
+
+    #[cfg(feature = "symbolize")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__symbolizing_raw_backtrace() {
+        let error_link = ErrorLink_::<String>::new_capturing::<crate::RawBacktrace>("Underlying error.")
+            .link::<String>("Higher level error.");
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        is_output_default(&format_output);
+
+        let raw_backtraces = crate::RawBacktrace::parse_all(&format_output);
+        assert_eq!(raw_backtraces.len(), 1);
+        assert_eq!(
+            raw_backtraces[0].to_string(),
+            error_link.end_capture().map(ToString::to_string).expect("look above")
+        );
+        let frames = raw_backtraces[0]
+            .symbolize(std::env::current_exe().expect("look above"))
+            .expect("look above");
+        println!("{:#?}", frames);
+        assert!(frames.iter().any(|frame| frame.symbol.contains("test__symbolizing_raw_backtrace")));
+
+        let raw_backtrace = crate::RawBacktrace {
+            build_id: None, load_address: None, instruction_pointers: vec![0x55d1c0de1234]
+        };
+        assert!(raw_backtrace.to_string().starts_with("build-id unknown, load address unknown, unsymbolized:\n"));
+        assert_eq!(crate::RawBacktrace::parse_all(&raw_backtrace.to_string()), std::slice::from_ref(&raw_backtrace));
+        assert!(raw_backtrace.symbolize(std::env::current_exe().expect("look above")).is_err());
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__parsing_frames() {
+        let frame = |number, symbol: &str, file: Option<&str>, line, column| crate::Frame {
+            number, symbol: symbol.to_string(), file: file.map(String::from), line, column
+        };
+        let backtrace = "   0: micro_errors::tests::function
+             at ./src/lib.rs:7:26
+      micro_errors::tests::inlined
+             at ./src/lib.rs:9
+   1:     0x55d1c0de - main
+   2: <unknown>
+";
+        assert_eq!(
+            crate::Frame::parse_all(backtrace),
+            [
+                frame(0, "micro_errors::tests::function", Some("./src/lib.rs"), Some(7), Some(26)),
+                frame(0, "micro_errors::tests::inlined", Some("./src/lib.rs"), Some(9), None),
+                frame(1, "main", None, None, None),
+                frame(2, "<unknown>", None, None, None)
+            ]
+        );
+        assert!(crate::Frame::parse_all("disabled backtrace").is_empty());
+
+        let error_link = ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error.");
+        let frames = error_link.backtrace_frames();
+        assert!(frames.is_empty() || frames.iter().any(|frame| frame.symbol.contains("test__parsing_frames")));
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__resolving_frames() {
+        let frames = crate::Frame::resolve_all(&backtrace::Backtrace::new());
+        assert!(frames.iter().any(|frame| frame.symbol.contains("test__resolving_frames")));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[allow(non_snake_case)]
+    fn test__filtering_frames() {
+        /// Removes the frame filters added while it was held.
+        struct FrameFiltersScope(usize);
+        impl Drop for FrameFiltersScope {
+            fn drop(&mut self) {
+                crate::frame_filters::FRAME_FILTERS.write().unwrap_or_else(|e| e.into_inner()).truncate(self.0);
+            }
+        }
+        let _frame_filters_scope = FrameFiltersScope(
+            crate::frame_filters::FRAME_FILTERS.read().unwrap_or_else(|e| e.into_inner()).len()
+        );
+
+        crate::add_frame_filter(|frame| frame.symbol.starts_with("filtered_out::"));
+        let backtrace = "   0: std::backtrace::Backtrace::capture
+             at /rustc/library/std/src/backtrace.rs:296:9
+   1: micro_errors::ErrorLink_<alloc::string::String>::new_string
+             at ./src/error_link_.rs:150:51
+      micro_errors::ErrorLink_<alloc::string::String>::new
+             at ./src/error_link_.rs:40:9
+   2: <micro_errors::ErrorLink_<alloc::string::String> as core::fmt::Display>::fmt
+             at ./src/error_link_.rs:250:9
+   3: user_crate::function
+             at ./src/main.rs:7:26
+      user_crate::inlined
+             at ./src/main.rs:12:5
+   4: user_crate::micro_errors::wrap
+             at ./src/micro_errors.rs:3:5
+   5: micro_errors_extras::wrap
+             at ./src/lib.rs:3:5
+   6:     0x55d1c0de - filtered_out::function
+   7: core::ops::function::FnOnce::call_once
+             at /rustc/library/core/src/ops/function.rs:250:5
+";
+        assert_eq!(
+            crate::frames::render_frames(&crate::Frame::parse_all(backtrace), 0),
+            "      [4 frames hidden]
+   3: user_crate::function
+             at ./src/main.rs:7:26
+      user_crate::inlined
+             at ./src/main.rs:12:5
+   4: user_crate::micro_errors::wrap
+             at ./src/micro_errors.rs:3:5
+   5: micro_errors_extras::wrap
+             at ./src/lib.rs:3:5
+      [2 frames hidden]
+"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[allow(non_snake_case)]
+    fn test__source_snippets() {
+        let snippet_line = line!() as usize;
+        let backtrace = format!("   1: user_crate::test__source_snippets
+             at ./src/lib.rs:{snippet_line}:28
+   2: user_crate::function
+             at ./src/missing.rs:7:26
+");
+        let with_snippets = crate::frames::render_frames(&crate::Frame::parse_all(&backtrace), 1);
+        println!("{}", with_snippets);
+        assert_eq!(
+            with_snippets.lines().nth(2 + 2),
+            Some(format!("        >{snippet_line:>5} |         let snippet_line = line!() as usize;").as_str())
+        );
+        assert_eq!(with_snippets.lines().count(), 2 + 5 + 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[allow(non_snake_case)]
+    fn test__deterministic_rendering() {
+        let error_link = Err::<(), _>(ErrorLink_::new_string("Underlying error."))
+            .map_err(|e| e.link::<String>("Higher level error."))
+            .me_trace()
+            .expect_err("look above");
+        let format_output = error_link.to_deterministic_string();
+        println!("{}", format_output);
+        assert_eq!(
+            format_output,
+            "An error occurred.\nLink no. 0: Higher level error.\nLink no. 1: Underlying error.\n\
+            Approximate backtrace of link no. 1:\n<backtrace>\n\
+            Return trace of link no. 0:\n   0: src/lib.rs:<line>:<column>\n"
+        );
+    }
+
+    #[cfg(feature = "insta")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__error_snapshot() {
+        let error_link = ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error.");
+        crate::assert_error_snapshot!(error_link, @r"
+        An error occurred.
+        Link no. 0: Higher level error.
+        Link no. 1: Underlying error.
+        Approximate backtrace of link no. 1:
+        <backtrace>
+        ");
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__return_trace__question_mark() {
+        fn underlying() -> Result<(), ErrorLink_<String>> {
+            Err(ErrorLink_::new_string("Underlying error."))
+        }
+        fn middle() -> Traced<(), String> {
+            underlying()?;
+            Traced(Ok(()))
+        }
+        fn higher() -> Result<(), ErrorLink_<String>> {
+            middle()?;
+            Ok(())
+        }
+        let error_link = higher().expect_err("look above");
+        let return_lines = error_link.return_trace().iter().map(|location| location.line()).collect::<Vec<_>>();
+        assert_eq!(return_lines.len(), 2);
+        assert_eq!(return_lines[0] + 4, return_lines[1]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[allow(non_snake_case)]
+    fn test__context_scopes() {
+        let _request_scope = crate::context_scope("Handling request 42.");
+        let error_link = {
+            let _user_scope = crate::context_scope("Loading user 7.");
+            Err::<(), _>(std::io::Error::other("Underlying error."))
+                .map_err(|e| e.link("Higher level error."))
+                .expect_err("look above")
+        };
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        is_output_default(&format_output);
+        assert!(error_link.context_scopes().is_empty());
+        assert_eq!(
+            error_link.links().next().expect("look above").context_scopes(), 
+            ["Handling request 42.", "Loading user 7."]
+        );
+        assert!(format_output.contains(
+            "Context scopes of link no. 1:\n   0: Handling request 42.\n   1: Loading user 7.\n"
+        ));
+
+        assert_eq!(ErrorLink_::new_string("Another error.").context_scopes(), ["Handling request 42."]);
+        drop(_request_scope);
+        assert!(ErrorLink_::new_string("Another error.").context_scopes().is_empty());
+
+        let outer_scope = crate::context_scope("Outer scope.");
+        let inner_scope = crate::context_scope("Inner scope.");
+        drop(outer_scope);
+        assert_eq!(ErrorLink_::new_string("Another error.").context_scopes(), ["Inner scope."]);
+        drop(inner_scope);
+        assert!(ErrorLink_::new_string("Another error.").context_scopes().is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+        loop {
+            if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+    #[cfg(feature = "std")]
+    struct YieldOnce(bool);
+    #[cfg(feature = "std")]
+    impl std::future::Future for YieldOnce {
+        type Output = ();
+        fn poll(
+            mut self: std::pin::Pin<&mut Self>, context: &mut std::task::Context<'_>
+        ) -> std::task::Poll<()> {
+            if self.0 {
+                return std::task::Poll::Ready(());
+            }
+            self.0 = true;
+            context.waker().wake_by_ref();
+            std::task::Poll::Pending
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[allow(non_snake_case)]
+    fn test__linking_futures() {
+        let error_link = block_on(
+            async {
+                YieldOnce(false).await;
+                Err::<(), _>(ErrorLink_::new_string("Underlying error."))
+            }
+                .me_link::<String>("Higher level error.")
+                .in_context("Handling request 42.")
+        ).expect_err("look above");
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        is_output_default(&format_output);
+        assert_eq!(
+            error_link.links().next().expect("look above").context_scopes(), 
+            ["Handling request 42."]
+        );
+        assert!(ErrorLink_::new_string("Another error.").context_scopes().is_empty());
+
+        fn is_send<T: Send>(_: &T) {}
+        is_send(&async { Ok::<(), ErrorLink_<String>>(()) }.me_link::<String>("").in_context(""));
+
+        let error_link = block_on(
+            async { Err::<(), _>(ErrorLink_::new_i32(100)) }.me_link_with::<String, _>(|| "Higher level error.")
+        ).expect_err("look above");
+        assert_eq!(error_link.0, "Higher level error.");
+        let error_link = block_on(async { Err::<(), _>(ErrorLink_::new_i32(100)) }.me_as_slink())
+            .expect_err("look above");
+        assert_eq!(error_link.0, "100");
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__linking_streams() {
+        struct Items(std::vec::IntoIter<Result<i32, ErrorLink_<String>>>);
+        impl futures_core::Stream for Items {
+            type Item = Result<i32, ErrorLink_<String>>;
+            fn poll_next(
+                mut self: std::pin::Pin<&mut Self>, _: &mut std::task::Context<'_>
+            ) -> std::task::Poll<Option<Self::Item>> {
+                std::task::Poll::Ready(self.0.next())
+            }
+        }
+        let items = Items(vec![Ok(1), Err(ErrorLink_::new_string("Underlying error."))].into_iter())
+            .me_link::<String, _>("Higher level error.")
+            .in_context("Handling request 42.");
+        let mut items = std::pin::pin!(items);
+        let mut next_item = || block_on(std::future::poll_fn(|context| futures_core::Stream::poll_next(items.as_mut(), context)));
+        assert_eq!(next_item().map(|item| item.expect("look above")), Some(1));
+        let error_link = next_item().expect("look above").expect_err("look above");
+        is_output_default(&format!("{error_link}"));
+        assert!(next_item().is_none());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__recording_tracing_events() {
+        use std::sync::{Arc, Mutex};
+        use tracing_subscriber::layer::SubscriberExt;
+
+        struct RecordedFields(Arc<Mutex<Vec<(String, String)>>>);
+        impl tracing::field::Visit for RecordedFields {
+            fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                self.0.lock().expect("look above").push((field.name().to_string(), format!("{value:?}")));
+            }
+        }
+        struct RecordingLayer(Arc<Mutex<Vec<(String, String)>>>);
+        impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for RecordingLayer {
+            fn on_event(&self, event: &tracing::Event<'_>, _: tracing_subscriber::layer::Context<'_, S>) {
+                let level = event.metadata().level().to_string();
+                let target = event.metadata().target().to_string();
+                self.0.lock().expect("look above").extend([
+                    (String::from("level"), level), (String::from("target"), target)
+                ]);
+                event.record(&mut RecordedFields(self.0.clone()));
+            }
+        }
+
+        let recorded_fields = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_error::ErrorLayer::default())
+            .with(RecordingLayer(recorded_fields.clone()));
+        let error_link = tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::info_span!("handling_request", request_id = 42).entered();
+            let error_link = ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error.");
+            error_link.record(tracing::Level::WARN);
+            error_link
+        });
+
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        is_output_default(&format_output);
+        assert!(format_output.contains("Span trace of link no. 1:\n"));
+        assert!(format_output.contains("handling_request\n           with request_id=42"));
+        let recorded_fields = recorded_fields.lock().expect("look above");
+        let events = recorded_fields.split(|(name, _)| name == "level").skip(1).collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        assert_eq!(recorded_fields.iter().filter(|(name, value)| name == "level" && value == "WARN").count(), 2);
+        assert_eq!(
+            recorded_fields.iter().filter(|(name, value)| name == "target" && value == "micro_errors").count(), 2
+        );
+        assert!(events[0].contains(&(String::from("error.link_number"), String::from("0"))));
+        assert!(events[0].contains(&(String::from("error.message"), String::from("Higher level error."))));
+        assert!(events[0].contains(&(String::from("error.link_count"), String::from("2"))));
+        assert!(events[0].iter().any(|(name, value)| {
+            name == "error.span_trace" && value.contains("handling_request")
+        }));
+        assert!(events[1].contains(&(String::from("error.link_number"), String::from("1"))));
+        assert!(events[1].contains(&(String::from("error.message"), String::from("Underlying error."))));
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__compact_formatting() {
+        let error_link = ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error.");
+        assert_eq!(format!("{error_link:#}"), "Higher level error.: Underlying error.");
+        let error_link: ErrorLink_<String> = ErrorLink_::new_many(
+            "Higher level error.", vec![error_link, ErrorLink_::new_string("Another error.")]
+        );
+        assert_eq!(
+            format!("{error_link:#}"),
+            "Higher level error.: [Higher level error.: Underlying error.; Another error.]"
+        );
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__logging_with_key_values() {
+        use std::sync::Mutex;
+
+        type KeyValuePairs = Vec<(String, String)>;
+        static LOGGED: Mutex<Vec<(String, String, KeyValuePairs)>> = Mutex::new(Vec::new());
+        struct RecordingLogger;
+        impl log::Log for RecordingLogger {
+            fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+                metadata.level() <= log::Level::Warn && metadata.target() == module_path!()
+            }
+            fn log(&self, record: &log::Record<'_>) {
+                struct KeyValues(Vec<(String, String)>);
+                impl<'kvs> log::kv::VisitSource<'kvs> for KeyValues {
+                    fn visit_pair(
+                        &mut self, key: log::kv::Key<'kvs>, value: log::kv::Value<'kvs>
+                    ) -> Result<(), log::kv::Error> {
+                        self.0.push((key.to_string(), value.to_string()));
+                        Ok(())
+                    }
+                }
+                let mut key_values = KeyValues(Vec::new());
+                record.key_values().visit(&mut key_values).expect("look above");
+                LOGGED.lock().expect("look above").push((
+                    record.target().to_string(), record.args().to_string(), key_values.0
+                ));
+            }
+            fn flush(&self) {}
+        }
+        log::set_logger(&RecordingLogger).expect("look above");
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let result = Err::<(), _>(ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error."))
+            .me_log_err(log::Level::Warn, module_path!());
+        assert!(result.is_err());
+        let _ = Err::<(), _>(ErrorLink_::new_string("Disabled error.")).me_log_err(log::Level::Info, module_path!());
+        ErrorLink_::new_string("Filtered out error.").log(log::Level::Warn, "other_crate");
+        let logged = LOGGED.lock().expect("look above");
+        assert_eq!(logged.len(), 1);
+        assert_eq!(logged[0].0, "micro_errors::tests");
+        assert_eq!(logged[0].1, "Higher level error.: Underlying error.");
+        assert_eq!(
+            logged[0].2,
+            [
+                (String::from("link.0"), String::from("Higher level error.")),
+                (String::from("link.1"), String::from("Underlying error."))
+            ]
+        );
+    }
+
+    #[cfg(feature = "anyhow")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__anyhow_conversions() {
+        let anyhow_error = ErrorLink_::new_string("Underlying error.")
+            .link::<String>("Higher level error.")
+            .into_anyhow();
+        assert_eq!(format!("{anyhow_error:#}"), "Higher level error.: Underlying error.");
+        assert_eq!(anyhow_error.chain().count(), 2);
+
+        let error_link = ErrorLink_::from_anyhow(anyhow_error);
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        is_output_default(&format_output);
+
+        let anyhow_error = anyhow::anyhow!("Underlying error.").context("Higher level error.");
+        let is_captured = anyhow_error.backtrace().status() == std::backtrace::BacktraceStatus::Captured;
+        let anyhow_frames = anyhow_error.backtrace().to_string();
+        let error_link = ErrorLink_::from_anyhow(anyhow_error);
+        is_output_default(&format!("{error_link}"));
+        assert_eq!(error_link.end_capture().is_some_and(|capture| capture.is_captured()), is_captured);
+        if is_captured {
+            assert_eq!(error_link.end_capture().map(ToString::to_string), Some(anyhow_frames));
+        }
+    }
+
+    #[cfg(feature = "eyre")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test__eyre_handler() {
+        crate::install_eyre_hook().expect("look above");
+        let report = eyre::Report::new(std::io::Error::other("Underlying error.")).wrap_err("Higher level error.");
+        let format_output = format!("{report:?}");
+        println!("{}", format_output);
+        is_output_default(&format_output);
+        assert_eq!(format!("{report:#}"), "Higher level error.: Underlying error.");
+
+        let report = eyre::Report::new(
+            ErrorLink_::new_string("Underlying error.").link::<String>("Higher level error.")
+        ).wrap_err("Highest level error.");
+        let format_output = format!("{report:?}");
+        println!("{}", format_output);
+        assert!(format_output.contains(
+            "Link no. 0: Highest level error.\nLink no. 1: Higher level error.\nLink no. 2: Underlying error.\n"
+        ));
+        has_only_one_backtrace(&format_output);
+        assert_eq!(format!("{report}"), "Highest level error.");
+        assert_eq!(format!("{report:#}"), "Highest level error.: Higher level error.: Underlying error.");
+
+        let report = eyre::Report::new(
+            ErrorLink_::new_message("Underlying error.").link::<crate::LinkMessage>("Higher level error.")
+        ).wrap_err("Highest level error.");
+        let format_output = format!("{report:?}");
+        assert!(format_output.contains(
+            "Link no. 0: Highest level error.\nLink no. 1: Higher level error.\nLink no. 2: Underlying error.\n"
+        ));
+        has_only_one_backtrace(&format_output);
+        assert_eq!(format!("{report:#}"), "Highest level error.: Higher level error.: Underlying error.");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[allow(non_snake_case)]
+    fn test__io_and_boxed_conversions() {
+        struct FailingWriter;
+        impl std::io::Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(ErrorLink_::new_string("Underlying error.")
+                    .link::<String>("Higher level error.")
+                    .into_io_error(std::io::ErrorKind::StorageFull))
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+        let io_error = std::io::Write::write(&mut FailingWriter, b"").expect_err("look above");
+        assert_eq!(io_error.kind(), std::io::ErrorKind::StorageFull);
+        let error_link = ErrorLink_::<String>::from_io_error(io_error).expect("look above");
+        let format_output = format!("{error_link}");
+        println!("{}", format_output);
+        is_output_default(&format_output);
+
+        let io_error = ErrorLink_::<String>::from_io_error(std::io::Error::other("Underlying error."))
+            .expect_err("look above");
+        assert_eq!(io_error.to_string(), "Underlying error.");
+
+        let boxed_error: Box<dyn std::error::Error + Send + Sync> = ErrorLink_::new_reason(ErrorReasons::One).into();
+        let error_link = ErrorLink_::<ErrorReasons>::from_boxed_error(boxed_error).expect("look above");
+        assert_eq!(error_link.0, ErrorReasons::One);
+    }
 }
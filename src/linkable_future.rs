@@ -0,0 +1,55 @@
+use alloc::string::String;
+use core::fmt::Display;
+use core::future::Future;
+#[cfg(feature = "std")]
+use crate::context_scope;
+use crate::{ErrorLink_, LinkableResult1of2};
+
+pub trait FutureLinkable<OkVariant, ErrorVariant>: Future<Output = Result<OkVariant, ErrorVariant>> {
+    fn me_link<ToPayload: Display>(self, error_payload: impl Into<ToPayload>)
+    -> impl Future<Output = Result<OkVariant, ErrorLink_<ToPayload>>>;
+    /// Like `me_link`, but the payload is only made should the future resolve to an error.
+    fn me_link_with<ToPayload: Display, IntoPayload: Into<ToPayload>>(
+        self, error_payload: impl FnOnce() -> IntoPayload
+    ) -> impl Future<Output = Result<OkVariant, ErrorLink_<ToPayload>>>;
+    fn me_as_slink(self) -> impl Future<Output = Result<OkVariant, ErrorLink_<String>>>;
+    /// Applies a context scope, see `context_scope`, each time the future is polled.
+    #[cfg(feature = "std")]
+    fn in_context(self, message: impl Into<String>) -> impl Future<Output = Self::Output>;
+}
+
+impl<Linkable, OkVariant, ErrorVariant> FutureLinkable<OkVariant, ErrorVariant> for Linkable
+where
+    Linkable: Future<Output = Result<OkVariant, ErrorVariant>>,
+    Result<OkVariant, ErrorVariant>: LinkableResult1of2<OkVariant>
+{
+    async fn me_link<ToPayload: Display>(self, error_payload: impl Into<ToPayload>)
+    -> Result<OkVariant, ErrorLink_<ToPayload>> {
+        self.await.me_link(error_payload)
+    }
+
+    async fn me_link_with<ToPayload: Display, IntoPayload: Into<ToPayload>>(
+        self, error_payload: impl FnOnce() -> IntoPayload
+    ) -> Result<OkVariant, ErrorLink_<ToPayload>> {
+        match self.await {
+            Ok(ok) => Ok(ok),
+            Err(e) => Err::<OkVariant, _>(e).me_link(error_payload())
+        }
+    }
+
+    async fn me_as_slink(self) -> Result<OkVariant, ErrorLink_<String>> {
+        self.await.me_as_slink()
+    }
+
+    #[cfg(feature = "std")]
+    fn in_context(self, message: impl Into<String>) -> impl Future<Output = Self::Output> {
+        let message = message.into();
+        async move {
+            let mut linkable = core::pin::pin!(self);
+            core::future::poll_fn(|cx| {
+                let _scope = context_scope(message.clone());
+                linkable.as_mut().poll(cx)
+            }).await
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::{ErrorLink_, ErrorLinkable};
+use crate::error_link_::as_slink;
+
+pub trait LinkableIterator<OkVariant, ErrorVariant>: Iterator<Item = Result<OkVariant, ErrorVariant>> {
+    /// Like collecting into a `Result<Vec<_>, _>`, but carries on past the first error, with
+    /// every error becoming a branch of the returned `NextLink::Many`.
+    fn collect_all_errors(self) -> Result<Vec<OkVariant>, ErrorLink_<String>>;
+    fn partition_links(self) -> (Vec<OkVariant>, Vec<ErrorLink_<String>>);
+}
+
+impl<Results, OkVariant, ErrorVariant> LinkableIterator<OkVariant, ErrorVariant> for Results
+where
+    Results: Iterator<Item = Result<OkVariant, ErrorVariant>>,
+    ErrorVariant: ErrorLinkable<ErrorVariant, String>
+{
+    fn collect_all_errors(self) -> Result<Vec<OkVariant>, ErrorLink_<String>> {
+        let (oks, error_links) = self.partition_links();
+        if error_links.is_empty() {
+            return Ok(oks);
+        }
+        Err(ErrorLink_::new_many(
+            format!("{} of {} results were errors.", error_links.len(), error_links.len() + oks.len()),
+            error_links
+        ))
+    }
+
+    fn partition_links(self) -> (Vec<OkVariant>, Vec<ErrorLink_<String>>) {
+        let mut oks = Vec::new();
+        let mut error_links = Vec::new();
+        for result in self {
+            match result {
+                Ok(ok) => oks.push(ok),
+                Err(error) => error_links.push(as_slink(error))
+            }
+        }
+        (oks, error_links)
+    }
+}
@@ -1,6 +1,11 @@
-use std::fmt::Display;
-use std::backtrace::Backtrace;
-use crate::{ErrorLink_, NextLink};
+use alloc::boxed::Box;
+use alloc::string::String;
+#[cfg(feature = "nightly")]
+use alloc::string::ToString;
+use core::fmt::Display;
+use crate::{Attachments, EndCapture, ErrorLink_, NextLink};
+#[cfg(feature = "nightly")]
+use crate::error_link_::capture_attachments;
 
 pub trait LinkableResult1of2<OkVariant> {
     fn me_link<ToPayload: Display>(self, error_payload: impl Into<ToPayload>)
@@ -9,39 +14,43 @@ pub trait LinkableResult1of2<OkVariant> {
 }
 
 #[cfg(feature = "nightly")]
-impl<OkVariant, ErrorVariant: Display> LinkableResult1of2<OkVariant>
+impl<OkVariant, ErrorVariant: Display + 'static> LinkableResult1of2<OkVariant>
 for Result<OkVariant, ErrorVariant> {
     default fn me_link<ToPayload: Display>(self, error_payload: impl Into<ToPayload>)
     -> Result<OkVariant, ErrorLink_<ToPayload>> {
         self.map_err(|e| {
             let next_link = Box::new(ErrorLink_(
-                e.to_string(),
-                NextLink::None(Backtrace::capture())
+                e.to_string().into(),
+                NextLink::None(EndCapture::capture()),
+                capture_attachments(&e)
             ));
-            ErrorLink_(error_payload.into(), NextLink::Some(next_link))
+            ErrorLink_(error_payload.into(), NextLink::Some(next_link), Attachments::new())
         })
     }
 
     default fn me_as_slink(self) -> Result<OkVariant, ErrorLink_<String>> {
         self.map_err(|e| ErrorLink_(
             e.to_string(),
-            NextLink::None(Backtrace::capture())
+            NextLink::None(EndCapture::capture()),
+            capture_attachments(&e)
         ))
     }
 }
 
-impl<OkVariant, FromPayload: Display> LinkableResult1of2<OkVariant>
+impl<OkVariant, FromPayload: Display + 'static> LinkableResult1of2<OkVariant>
 for Result<OkVariant, ErrorLink_<FromPayload>> {
     fn me_link<ToPayload: Display>(self, error_payload: impl Into<ToPayload>)
     -> Result<OkVariant, ErrorLink_<ToPayload>> {
         self.map_err(|e| {
-            let next_link = Box::new(ErrorLink_(e.0.to_string(), e.1));
-            ErrorLink_(error_payload.into(), NextLink::Some(next_link))
+            ErrorLink_(error_payload.into(), NextLink::Some(Box::new(e.into_lower())), Attachments::new())
         })
     }
 
     fn me_as_slink(self) -> Result<OkVariant, ErrorLink_<String>> {
-        self.map_err(|e| ErrorLink_(e.0.to_string(), e.1))
+        self.map_err(|e| {
+            let e = e.into_lower();
+            ErrorLink_(e.0.into_owned(), e.1, e.2)
+        })
     }
 }
 
@@ -54,12 +63,29 @@ impl<OkVariant, FromPayload: Display> LinkableResult2of2<OkVariant, FromPayload>
 for Result<OkVariant, ErrorLink_<FromPayload>> {
     fn me_as_link<ToPayload: From<FromPayload> + Display>(self)
     -> Result<OkVariant, ErrorLink_<ToPayload>> {
-        self.map_err(|e| ErrorLink_(e.0.into(), e.1))
+        self.map_err(|e| ErrorLink_(e.0.into(), e.1, e.2))
+    }
+}
+
+/// Apart from `LinkableResult2of2`, so that implementers of it are not made to implement this.
+pub trait TraceableResult<OkVariant, Payload: Display> {
+    /// Records the caller in the error's return trace, e.g., `function().me_trace()?`.
+    fn me_trace(self) -> Result<OkVariant, ErrorLink_<Payload>>;
+}
+
+impl<OkVariant, Payload: Display> TraceableResult<OkVariant, Payload>
+for Result<OkVariant, ErrorLink_<Payload>> {
+    #[track_caller]
+    fn me_trace(self) -> Result<OkVariant, ErrorLink_<Payload>> {
+        match self {
+            Ok(ok) => Ok(ok),
+            Err(e) => Err(e.trace())
+        }
     }
 }
 
 impl<P: Display> From<P> for ErrorLink_<P> {
     fn from(value: P) -> Self {
-        ErrorLink_(value, NextLink::None(Backtrace::capture()))
+        ErrorLink_(value, NextLink::None(EndCapture::capture()), Attachments::capture())
     }
 }
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+use crate::{context_scope, ErrorLink_, LinkableResult1of2};
+
+pub trait StreamLinkable<OkVariant, ErrorVariant>: Stream<Item = Result<OkVariant, ErrorVariant>> {
+    fn me_link<ToPayload: Display, IntoPayload: Into<ToPayload> + Clone>(self, error_payload: IntoPayload)
+    -> impl Stream<Item = Result<OkVariant, ErrorLink_<ToPayload>>>;
+    /// Like `me_link`, but payloads are only made for the items that are errors.
+    fn me_link_with<ToPayload: Display, IntoPayload: Into<ToPayload>>(
+        self, error_payload: impl FnMut() -> IntoPayload
+    ) -> impl Stream<Item = Result<OkVariant, ErrorLink_<ToPayload>>>;
+    fn me_as_slink(self) -> impl Stream<Item = Result<OkVariant, ErrorLink_<String>>>;
+    /// Applies a context scope, see `context_scope`, each time the stream is polled.
+    fn in_context(self, message: impl Into<String>) -> impl Stream<Item = Self::Item>;
+}
+
+impl<Linkable, OkVariant, ErrorVariant> StreamLinkable<OkVariant, ErrorVariant> for Linkable
+where
+    Linkable: Stream<Item = Result<OkVariant, ErrorVariant>>,
+    Result<OkVariant, ErrorVariant>: LinkableResult1of2<OkVariant>
+{
+    fn me_link<ToPayload: Display, IntoPayload: Into<ToPayload> + Clone>(self, error_payload: IntoPayload)
+    -> impl Stream<Item = Result<OkVariant, ErrorLink_<ToPayload>>> {
+        MapItems { items: self, map_item: move |item: Self::Item| item.me_link(error_payload.clone()) }
+    }
+
+    fn me_link_with<ToPayload: Display, IntoPayload: Into<ToPayload>>(
+        self, mut error_payload: impl FnMut() -> IntoPayload
+    ) -> impl Stream<Item = Result<OkVariant, ErrorLink_<ToPayload>>> {
+        let map_item = move |item: Self::Item| match item {
+            Ok(ok) => Ok(ok),
+            Err(e) => Err::<OkVariant, _>(e).me_link(error_payload())
+        };
+        MapItems { items: self, map_item }
+    }
+
+    fn me_as_slink(self) -> impl Stream<Item = Result<OkVariant, ErrorLink_<String>>> {
+        MapItems { items: self, map_item: LinkableResult1of2::me_as_slink }
+    }
+
+    fn in_context(self, message: impl Into<String>) -> impl Stream<Item = Self::Item> {
+        InContext { items: self, message: message.into() }
+    }
+}
+
+pin_project! {
+    struct MapItems<Items, MapItem> {
+        #[pin]
+        items: Items,
+        map_item: MapItem
+    }
+}
+
+impl<Items: Stream, MapItem: FnMut(Items::Item) -> Mapped, Mapped> Stream for MapItems<Items, MapItem> {
+    type Item = Mapped;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Mapped>> {
+        let this = self.project();
+        this.items.poll_next(cx).map(|item| item.map(this.map_item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+pin_project! {
+    struct InContext<Items> {
+        #[pin]
+        items: Items,
+        message: String
+    }
+}
+
+impl<Items: Stream> Stream for InContext<Items> {
+    type Item = Items::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Items::Item>> {
+        let this = self.project();
+        let _scope = context_scope(this.message.clone());
+        this.items.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt::Display;
+use std::panic::Location;
+use log::Level;
+use crate::{Attachments, ErrorLink_};
+use crate::context_scope::ContextScopes;
+use crate::return_trace::ReturnTrace;
+use crate::suppressed::Suppressed;
+
+impl<Payload: Display> ErrorLink_<Payload> {
+    /// Logs the messages on one line, as with `{:#}`, rather than the whole report. Each link's
+    /// message, and what is attached to it, is also logged as key-values, e.g., `link.1`. Nothing
+    /// is formatted unless the logger is enabled for `level` and `target`. The target is to be
+    /// `module_path!()` for the record to be filtered as if by `log`'s macros in the caller's module.
+    #[track_caller]
+    pub fn log(&self, level: Level, target: &str) {
+        let metadata = log::Metadata::builder().level(level).target(target).build();
+        if level > log::max_level() || !log::logger().enabled(&metadata) {
+            return;
+        }
+        let location = Location::caller();
+        let mut key_values = Vec::new();
+        push_key_values(&mut key_values, 0, &self.0, &self.2);
+        for (link_number, error_link) in self.links().enumerate() {
+            push_key_values(&mut key_values, link_number + 1, &error_link.0, &error_link.2);
+        }
+        let key_values = key_values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect::<Vec<_>>();
+        log::logger().log(
+            &log::Record::builder()
+                .args(format_args!("{self:#}"))
+                .metadata(metadata)
+                .file(Some(location.file()))
+                .line(Some(location.line()))
+                .key_values(&key_values.as_slice())
+                .build()
+        );
+    }
+}
+
+fn push_key_values(
+    key_values: &mut Vec<(String, String)>, link_number: usize, payload: &dyn Display, attachments: &Attachments
+) {
+    key_values.push((format!("link.{link_number}"), payload.to_string()));
+    let context_scopes = ContextScopes::of(attachments);
+    if !context_scopes.is_empty() {
+        key_values.push((format!("link.{link_number}.context_scopes"), context_scopes.join("; ")));
+    }
+    let return_trace = ReturnTrace::of(attachments);
+    if !return_trace.is_empty() {
+        let return_trace = return_trace.iter().map(ToString::to_string).collect::<Vec<_>>();
+        key_values.push((format!("link.{link_number}.return_trace"), return_trace.join(", ")));
+    }
+    let suppressed = Suppressed::of(attachments);
+    if !suppressed.is_empty() {
+        let suppressed = suppressed.iter().map(|error_link| format!("{error_link:#}")).collect::<Vec<_>>();
+        key_values.push((format!("link.{link_number}.suppressed"), suppressed.join("; ")));
+    }
+}
+
+pub trait LoggableResult<OkVariant, Payload: Display> {
+    /// Logs the error, if any, see `ErrorLink_::log`, then passes the result through unchanged.
+    /// The target is to be `module_path!()`, as with `log`.
+    fn me_log_err(self, level: Level, target: &str) -> Result<OkVariant, ErrorLink_<Payload>>;
+}
+
+impl<OkVariant, Payload: Display> LoggableResult<OkVariant, Payload>
+for Result<OkVariant, ErrorLink_<Payload>> {
+    #[track_caller]
+    fn me_log_err(self, level: Level, target: &str) -> Result<OkVariant, ErrorLink_<Payload>> {
+        if let Err(error_link) = &self {
+            error_link.log(level, target);
+        }
+        self
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::path::Path;
+use std::sync::OnceLock;
+use object::{Object, ObjectSegment, ReadCache};
+use crate::{Capture, ErrorLink_, ErrorLinkable, Frame};
+
+/// Captures only the instruction pointers, along with the build-id and load address of the
+/// running binary, which is cheap enough for release builds. Its `Display` is parsed back by
+/// `RawBacktrace::parse_all`, for `symbolize` to be run later, against the unstripped binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawBacktrace {
+    /// In hex, if the binary has one.
+    pub build_id: Option<String>,
+    /// What is subtracted from instruction pointers to have addresses as in the binary's file, if
+    /// it could be found, see `mapped_address`.
+    pub load_address: Option<usize>,
+    pub instruction_pointers: Vec<usize>
+}
+
+/// The build-id and load address of the running binary, which are only looked up once. Only its
+/// headers and notes are read, rather than the whole file.
+fn running_binary() -> &'static (Option<String>, Option<usize>) {
+    static RUNNING_BINARY: OnceLock<(Option<String>, Option<usize>)> = OnceLock::new();
+    RUNNING_BINARY.get_or_init(|| {
+        let Some(binary) = std::env::current_exe().ok().and_then(|path| File::open(path).ok()) else {
+            return (None, None);
+        };
+        let binary = ReadCache::new(binary);
+        let Ok(binary) = object::File::parse(&binary) else {
+            return (None, None);
+        };
+        let build_id = binary.build_id().ok().flatten().map(to_hex);
+        let first_address = binary
+            .segments()
+            .find(|segment| segment.file_range().0 == 0)
+            .map_or(0, |segment| segment.address() as usize);
+        (build_id, mapped_address().map(|mapped_address| mapped_address - first_address))
+    })
+}
+
+/// Where the start of the running binary's file is mapped, as listed by `/proc/self/maps`. Only
+/// Linux has it, so elsewhere the load address is left unknown, and cannot be symbolized against.
+#[cfg(target_os = "linux")]
+fn mapped_address() -> Option<usize> {
+    let binary_path = std::fs::canonicalize(std::env::current_exe().ok()?).ok()?;
+    let maps = std::fs::read_to_string("/proc/self/maps").ok()?;
+    maps.lines().find_map(|mapping| {
+        // E.g., "55d1c0de0000-55d1c0de4000 r--p 00000000 08:01 1234 /path/to/binary".
+        let mut mapping_parts = mapping.split_whitespace();
+        let (address_range, _, offset) = (mapping_parts.next()?, mapping_parts.next()?, mapping_parts.next()?);
+        let path = mapping_parts.nth(2)?;
+        if Path::new(path) != binary_path || usize::from_str_radix(offset, 16).ok()? != 0 {
+            return None;
+        }
+        usize::from_str_radix(address_range.split_once('-')?.0, 16).ok()
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn mapped_address() -> Option<usize> {
+    None
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+impl Capture for RawBacktrace {
+    fn capture() -> Self {
+        let (build_id, load_address) = running_binary().clone();
+        let mut instruction_pointers = Vec::new();
+        backtrace::trace(|frame| {
+            instruction_pointers.push(frame.ip() as usize);
+            true
+        });
+        RawBacktrace { build_id, load_address, instruction_pointers }
+    }
+}
+
+/// E.g., "build-id 3f2a.., load address 0x55d1c0de0000, unsymbolized:\n   0: 0x55d1c0de1234\n",
+/// where either may be "unknown".
+impl Display for RawBacktrace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let build_id = self.build_id.as_deref().unwrap_or("unknown");
+        let load_address = self
+            .load_address
+            .map_or_else(|| String::from("unknown"), |load_address| format!("{load_address:#x}"));
+        writeln!(f, "build-id {build_id}, load address {load_address}, unsymbolized:")?;
+        for (frame_number, instruction_pointer) in self.instruction_pointers.iter().enumerate() {
+            writeln!(f, "{frame_number:>4}: {instruction_pointer:#x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl RawBacktrace {
+    /// Finds every `RawBacktrace` rendered within `text`, e.g., a report or a log.
+    pub fn parse_all(text: &str) -> Vec<RawBacktrace> {
+        let mut raw_backtraces = Vec::new();
+        let mut lines = text.lines().peekable();
+        while let Some(line) = lines.next() {
+            let Some(header) = line.trim_start().strip_prefix("build-id ") else {
+                continue;
+            };
+            let Some((build_id, load_address)) = header
+                .strip_suffix(", unsymbolized:")
+                .and_then(|header| header.split_once(", load address ")) else {
+                continue;
+            };
+            let load_address = match load_address.strip_prefix("0x") {
+                Some(load_address) => match usize::from_str_radix(load_address, 16) {
+                    Ok(load_address) => Some(load_address),
+                    Err(_) => continue
+                },
+                None if load_address == "unknown" => None,
+                None => continue
+            };
+            let mut instruction_pointers = Vec::new();
+            while let Some(instruction_pointer) = lines.peek().and_then(|line| parse_address_line(line)) {
+                instruction_pointers.push(instruction_pointer);
+                lines.next();
+            }
+            raw_backtraces.push(RawBacktrace {
+                build_id: (build_id != "unknown").then(|| build_id.to_string()),
+                load_address,
+                instruction_pointers
+            });
+        }
+        raw_backtraces
+    }
+
+    /// Resolves the frames against `binary`, which must be the unstripped build of the binary
+    /// that captured them, as is checked by build-id where there is one.
+    pub fn symbolize(&self, binary: impl AsRef<Path>) -> Result<Vec<Frame>, ErrorLink_<String>> {
+        let binary = binary.as_ref();
+        let Some(load_address) = self.load_address else {
+            return Err(ErrorLink_::new(String::from(
+                "The load address is unknown, as it is only found on Linux, so nothing can be symbolized."
+            )));
+        };
+        let binary_data = File::open(binary)
+            .map(ReadCache::new)
+            .map_err(|e| e.link(format!("Reading {} failed.", binary.display())))?;
+        let build_id = object::File::parse(&binary_data)
+            .map_err(|e| e.link(format!("Parsing {} failed.", binary.display())))?
+            .build_id()
+            .ok()
+            .flatten()
+            .map(to_hex);
+        if let (Some(expected), Some(actual)) = (&self.build_id, &build_id) {
+            if expected != actual {
+                return Err(ErrorLink_::new(format!(
+                    "The build-id of {} is {actual}, not {expected}.", binary.display()
+                )));
+            }
+        }
+        let loader = addr2line::Loader::new(binary)
+            .map_err(|e| ErrorLink_::new_string(e.to_string()).link(format!("Loading {} failed.", binary.display())))?;
+
+        let mut frames = Vec::new();
+        for (frame_number, instruction_pointer) in self.instruction_pointers.iter().enumerate() {
+            // Besides the first, these are return addresses, so are just past their call.
+            let call_offset = if frame_number == 0 { 0 } else { 1 };
+            let address = instruction_pointer.wrapping_sub(load_address).wrapping_sub(call_offset) as u64;
+            let frames_before = frames.len();
+            if let Ok(mut address_frames) = loader.find_frames(address) {
+                while let Ok(Some(address_frame)) = address_frames.next() {
+                    let location = address_frame.location.as_ref();
+                    frames.push(Frame {
+                        number: frame_number,
+                        symbol: address_frame
+                            .function
+                            .as_ref()
+                            .and_then(|function| function.demangle().ok())
+                            .map_or_else(|| String::from("<unknown>"), |symbol| symbol.into_owned()),
+                        file: location.and_then(|location| location.file).map(String::from),
+                        line: location.and_then(|location| location.line),
+                        column: location.and_then(|location| location.column)
+                    });
+                }
+            }
+            if frames.len() == frames_before {
+                let symbol = loader.find_symbol(address).map_or_else(
+                    || format!("<unknown at {instruction_pointer:#x}>"),
+                    |symbol| addr2line::demangle_auto(symbol.into(), None).into_owned()
+                );
+                frames.push(Frame { number: frame_number, symbol, file: None, line: None, column: None });
+            }
+        }
+        Ok(frames)
+    }
+}
+
+/// An "   N: 0x55d1c0de1234" line.
+fn parse_address_line(line: &str) -> Option<usize> {
+    let (frame_number, address) = line.trim_start().split_once(": ")?;
+    frame_number.parse::<usize>().ok()?;
+    usize::from_str_radix(address.trim().strip_prefix("0x")?, 16).ok()
+}
@@ -1,6 +1,9 @@
-use std::fmt::Display;
-use std::backtrace::Backtrace;
-use crate::{ErrorLink_, NextLink};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use crate::{Attachments, EndCapture, ErrorLink_, NextLink};
+#[cfg(feature = "nightly")]
+use crate::error_link_::capture_attachments;
 
 #[cfg(feature = "nightly")]
 pub trait ResultExt<OkVariant, ToPayload: Display> {
@@ -10,23 +13,25 @@ pub trait ResultExt<OkVariant, ToPayload: Display> {
 }
 
 #[cfg(feature = "nightly")]
-impl<OkVariant, ErrorVariant: Display> ResultExt<OkVariant, String> 
+impl<OkVariant, ErrorVariant: Display + 'static> ResultExt<OkVariant, String> 
 for Result<OkVariant, ErrorVariant> {
     default fn me_l(self, error_payload: impl Into<String>)
     -> Result<OkVariant, ErrorLink_<String>> {
         self.map_err(|e| {
             let next_link = Box::new(ErrorLink_(
-                e.to_string(), 
-                NextLink::None(Backtrace::capture()))
+                e.to_string().into(),
+                NextLink::None(EndCapture::capture()),
+                capture_attachments(&e))
             );
-            ErrorLink_(error_payload.into(), NextLink::Some(next_link))
+            ErrorLink_(error_payload.into(), NextLink::Some(next_link), Attachments::new())
         })
     }
 
     default fn me_al(self) -> Result<OkVariant, ErrorLink_<String>> {
         self.map_err(|e| ErrorLink_(
             e.to_string(),
-            NextLink::None(Backtrace::capture())
+            NextLink::None(EndCapture::capture()),
+            capture_attachments(&e)
         ))
     }
 }
@@ -37,37 +42,40 @@ for Result<OkVariant, String> {
     fn me_l(self, error_payload: impl Into<String>)
     -> Result<OkVariant, ErrorLink_<String>> {
         self.map_err(|e| {
-            let next_link = Box::new(ErrorLink_(e, 
-                NextLink::None(Backtrace::capture()))
+            let next_link = Box::new(ErrorLink_(e.into(), 
+                NextLink::None(EndCapture::capture()),
+                Attachments::capture())
             );
-            ErrorLink_(error_payload.into(), NextLink::Some(next_link))
+            ErrorLink_(error_payload.into(), NextLink::Some(next_link), Attachments::new())
         })
     }
 
     fn me_al(self) -> Result<OkVariant, ErrorLink_<String>> {
         self.map_err(|e| ErrorLink_(
             e,
-            NextLink::None(Backtrace::capture())
+            NextLink::None(EndCapture::capture()),
+            Attachments::capture()
         ))
     }
 }
 
 #[cfg(feature = "nightly")]
-impl<OkVariant, FromPayload: Display> ResultExt<OkVariant, String> 
+impl<OkVariant, FromPayload: Display + 'static> ResultExt<OkVariant, String> 
 for Result<OkVariant, ErrorLink_<FromPayload>> {
     default fn me_l(self, error_payload: impl Into<String>)
     -> Result<OkVariant, ErrorLink_<String>> {
-        self.map_err(|e| {
-            let next_link = Box::new(ErrorLink_(e.0.to_string(), e.1));
-            ErrorLink_(
-                error_payload.into(),
-                NextLink::Some(next_link)
-            )
-        })
+        self.map_err(|e| ErrorLink_(
+            error_payload.into(),
+            NextLink::Some(Box::new(e.into_lower())),
+            Attachments::new()
+        ))
     }
 
     default fn me_al(self) -> Result<OkVariant, ErrorLink_<String>> {
-        self.map_err(|e| ErrorLink_(e.0.to_string(), e.1))
+        self.map_err(|e| {
+            let e = e.into_lower();
+            ErrorLink_(e.0.into_owned(), e.1, e.2)
+        })
     }
 }
 
@@ -78,7 +86,8 @@ for Result<OkVariant, ErrorLink_<String>> {
     -> Result<OkVariant, ErrorLink_<String>> {
         self.map_err(|e| ErrorLink_(
             error_payload.into(),
-            NextLink::Some(Box::new(e))
+            NextLink::Some(Box::new(e.into_lower())),
+            Attachments::new()
         ))
     }
 
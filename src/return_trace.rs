@@ -0,0 +1,111 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::panic::Location;
+use crate::{Attachments, ErrorLink_};
+#[cfg(feature = "nightly")]
+use core::convert::Infallible;
+#[cfg(feature = "nightly")]
+use core::ops::{ControlFlow, FromResidual, Residual, Try};
+
+/// Sites a link was returned through, oldest first.
+pub(crate) struct ReturnTrace(Vec<&'static Location<'static>>);
+
+impl ReturnTrace {
+    pub(crate) fn of(attachments: &Attachments) -> &[&'static Location<'static>] {
+        attachments.get::<ReturnTrace>().map_or(&[], |return_trace| &return_trace.0)
+    }
+}
+
+impl<Payload: Display> ErrorLink_<Payload> {
+    /// Records the caller as a site this link was returned through. Unlike a `Backtrace`, this
+    /// is cheap, and is available in release builds.
+    #[track_caller]
+    pub fn trace(mut self) -> Self {
+        let location = Location::caller();
+        match self.2.get_mut::<ReturnTrace>() {
+            Some(return_trace) => return_trace.0.push(location),
+            None => self.2.insert(ReturnTrace(vec![location]))
+        }
+        self
+    }
+
+    pub fn return_trace(&self) -> &[&'static Location<'static>] {
+        ReturnTrace::of(&self.2)
+    }
+}
+
+/// A `Result` whose errors record each `?` they are propagated through.
+#[cfg(feature = "nightly")]
+#[derive(Debug)]
+pub struct Traced<OkVariant, Payload: Display>(pub Result<OkVariant, ErrorLink_<Payload>>);
+
+#[cfg(feature = "nightly")]
+impl<OkVariant, Payload: Display> Traced<OkVariant, Payload> {
+    pub fn into_result(self) -> Result<OkVariant, ErrorLink_<Payload>> {
+        self.0
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<OkVariant, Payload: Display> From<Result<OkVariant, ErrorLink_<Payload>>>
+for Traced<OkVariant, Payload> {
+    fn from(result: Result<OkVariant, ErrorLink_<Payload>>) -> Self {
+        Self(result)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<OkVariant, Payload: Display> Try for Traced<OkVariant, Payload> {
+    type Output = OkVariant;
+    type Residual = Traced<Infallible, Payload>;
+
+    fn from_output(output: OkVariant) -> Self {
+        Self(Ok(output))
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, OkVariant> {
+        match self.0 {
+            Ok(output) => ControlFlow::Continue(output),
+            Err(error_link) => ControlFlow::Break(Traced(Err(error_link)))
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<OkVariant, Payload: Display> Residual<OkVariant> for Traced<Infallible, Payload> {
+    type TryType = Traced<OkVariant, Payload>;
+}
+
+#[cfg(feature = "nightly")]
+impl<OkVariant, Payload: Display> FromResidual<Traced<Infallible, Payload>>
+for Traced<OkVariant, Payload> {
+    #[track_caller]
+    fn from_residual(residual: Traced<Infallible, Payload>) -> Self {
+        let Err(error_link) = residual.0;
+        Self(Err(error_link.trace()))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<OkVariant, Payload: Display, ErrorVariant> FromResidual<Result<Infallible, ErrorVariant>>
+for Traced<OkVariant, Payload>
+where
+    ErrorLink_<Payload>: From<ErrorVariant>
+{
+    #[track_caller]
+    fn from_residual(residual: Result<Infallible, ErrorVariant>) -> Self {
+        let Err(error) = residual;
+        Self(Err(ErrorLink_::from(error).trace()))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<OkVariant, Payload: Display> FromResidual<Traced<Infallible, Payload>>
+for Result<OkVariant, ErrorLink_<Payload>> {
+    #[track_caller]
+    fn from_residual(residual: Traced<Infallible, Payload>) -> Self {
+        let Err(error_link) = residual.0;
+        Err(error_link.trace())
+    }
+}
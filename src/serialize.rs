@@ -0,0 +1,35 @@
+use std::fmt::Display;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use crate::{Capture, ErrorLink_, Frame, NextLink};
+
+/// The `backtrace` is serialized as its frames. A capture without frames, e.g., the task a chain
+/// started in, is serialized as rendered, as the `capture`.
+impl<Payload: Display> Serialize for ErrorLink_<Payload> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let end_capture = match &self.1 {
+            NextLink::None(end_capture) => Some(end_capture.as_capture())
+                .filter(|end_capture| end_capture.is_captured()),
+            _ => None
+        };
+        let backtrace = end_capture.and_then(Capture::frames);
+        let capture = end_capture.filter(|_| backtrace.is_none()).map(ToString::to_string);
+        let mut error_link = serializer.serialize_struct("ErrorLink_", 4)?;
+        error_link.serialize_field("message", &self.0.to_string())?;
+        error_link.serialize_field("causes", &self.1.next_links().as_slice())?;
+        error_link.serialize_field("backtrace", &backtrace)?;
+        error_link.serialize_field("capture", &capture)?;
+        error_link.end()
+    }
+}
+
+impl Serialize for Frame {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut frame = serializer.serialize_struct("Frame", 5)?;
+        frame.serialize_field("number", &self.number)?;
+        frame.serialize_field("symbol", &self.symbol)?;
+        frame.serialize_field("file", &self.file)?;
+        frame.serialize_field("line", &self.line)?;
+        frame.serialize_field("column", &self.column)?;
+        frame.end()
+    }
+}
@@ -0,0 +1,53 @@
+use alloc::string::String;
+use alloc::sync::Arc;
+use core::fmt::Display;
+use core::ops::Deref;
+use crate::{Attachments, ErrorLink_, LinkMessage, NextLink};
+
+/// An `ErrorLink_` behind an `Arc`, so that clones are O(1), e.g., to hand one failure to several
+/// waiters. Each clone can still be linked on top of.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SharedLink(Arc<ErrorLink_<LinkMessage>>);
+
+impl<Payload: Display + 'static> ErrorLink_<Payload> {
+    pub fn shared(self) -> SharedLink {
+        SharedLink(Arc::new(self.into_lower()))
+    }
+}
+
+impl SharedLink {
+    pub fn link<ToPayload: Display>(self, error_payload: impl Into<ToPayload>) -> ErrorLink_<ToPayload> {
+        ErrorLink_(error_payload.into(), NextLink::Shared(self.0), Attachments::new())
+    }
+
+    pub fn link_fn<ToPayload: Display>(error_payload: impl Into<ToPayload>) -> impl FnOnce(Self) -> ErrorLink_<ToPayload> {
+        move |shared_link| shared_link.link(error_payload)
+    }
+
+    /// Gives back the `ErrorLink_` if this is its last clone.
+    pub fn try_unwrap(self) -> Result<ErrorLink_<String>, Self> {
+        Arc::try_unwrap(self.0)
+            .map(|error_link| ErrorLink_(error_link.0.into_owned(), error_link.1, error_link.2))
+            .map_err(SharedLink)
+    }
+}
+
+impl Deref for SharedLink {
+    type Target = ErrorLink_<LinkMessage>;
+
+    fn deref(&self) -> &ErrorLink_<LinkMessage> {
+        &self.0
+    }
+}
+
+impl Display for SharedLink {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&*self.0, f)
+    }
+}
+
+impl core::error::Error for SharedLink {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.0.source()
+    }
+}
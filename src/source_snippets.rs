@@ -0,0 +1,49 @@
+use alloc::string::String;
+use core::fmt::Write;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Lines of source shown either side of the line of a frame.
+const CONTEXT_LINES: usize = 2;
+
+static SNIPPET_FRAMES: AtomicUsize = AtomicUsize::new(0);
+
+/// Renders a few lines of source around the top `frames` crate-local frames of each backtrace, and
+/// around each location of a return trace. Sources not on disk are skipped. Off, i.e., 0, by
+/// default, being meant for developer builds. Without `std`, there are no sources on disk.
+pub fn set_source_snippets(frames: usize) {
+    SNIPPET_FRAMES.store(frames, Ordering::Relaxed);
+}
+
+pub(crate) fn snippet_frames() -> usize {
+    SNIPPET_FRAMES.load(Ordering::Relaxed)
+}
+
+/// Writes the lines around `line_number` of `file`, if it is relative, i.e., of the crate being
+/// built rather than of `std` or a dependency, and can be read.
+pub(crate) fn write_snippet(f: &mut impl Write, file: &str, line_number: usize) -> core::fmt::Result {
+    let Some(source) = read_source(file) else {
+        return Ok(());
+    };
+    let first_line = line_number.saturating_sub(CONTEXT_LINES).max(1);
+    let last_line = line_number + CONTEXT_LINES;
+    let source_lines = source.lines().enumerate().skip(first_line - 1).take(last_line + 1 - first_line);
+    for (line_index, source_line) in source_lines {
+        let marker = if line_index + 1 == line_number { '>' } else { ' ' };
+        writeln!(f, "        {marker}{:>5} | {source_line}", line_index + 1)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+fn read_source(file: &str) -> Option<String> {
+    if std::path::Path::new(file).is_absolute() {
+        return None;
+    }
+    std::fs::read_to_string(file).ok()
+}
+
+#[cfg(not(feature = "std"))]
+fn read_source(_: &str) -> Option<String> {
+    None
+}
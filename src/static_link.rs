@@ -0,0 +1,147 @@
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use core::iter::once;
+use crate::{Attachments, EndCapture, ErrorLink_, LinkMessage, NextLink, NoCapture};
+use crate::deterministic::is_deterministic;
+
+/// A chain of `&'static str` messages held inline, with room for `CAPACITY` links, for where
+/// allocating is not possible, e.g., interrupt handlers or handling an allocation failure. Once
+/// full, the root cause and the newest links are kept, dropping those just above the root cause,
+/// which are counted and written as a "[N links omitted]" link in their place. With a `CAPACITY` of
+/// one, only the root cause is kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaticLink<const CAPACITY: usize> {
+    /// The root cause first.
+    messages: [&'static str; CAPACITY],
+    len: usize,
+    dropped: usize
+}
+
+impl<const CAPACITY: usize> StaticLink<CAPACITY> {
+    pub const fn new(error_message: &'static str) -> Self {
+        const { assert!(CAPACITY > 0, "a `StaticLink` needs room for at least its root cause") };
+        let mut messages = [""; CAPACITY];
+        messages[0] = error_message;
+        Self { messages, len: 1, dropped: 0 }
+    }
+
+    pub const fn link(mut self, error_message: &'static str) -> Self {
+        if self.len == CAPACITY {
+            // With room for the root cause alone, it is the new link that is dropped.
+            if CAPACITY == 1 {
+                self.dropped += 1;
+                return self;
+            }
+            let mut message_index = 1;
+            while message_index + 1 < CAPACITY {
+                self.messages[message_index] = self.messages[message_index + 1];
+                message_index += 1;
+            }
+            self.len -= 1;
+            self.dropped += 1;
+        }
+        self.messages[self.len] = error_message;
+        self.len += 1;
+        self
+    }
+
+    pub fn link_fn(error_message: &'static str) -> impl FnOnce(Self) -> Self {
+        move |static_link| static_link.link(error_message)
+    }
+
+    /// The messages of this link, then of each link below it, as `ErrorLink_::links` orders them.
+    /// Those dropped once full are left out, see `dropped`.
+    pub fn messages(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.messages[..self.len].iter().rev().copied()
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == CAPACITY
+    }
+
+    /// How many links were dropped for there being no room left.
+    pub const fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// The links as written, i.e., with the dropped ones as one link, in the order of `messages`.
+    fn written_links(&self) -> impl DoubleEndedIterator<Item = WrittenLink> + '_ {
+        let (root_cause, messages) = self.messages[..self.len]
+            .split_first()
+            .expect("has at least the root cause");
+        messages
+            .iter()
+            .rev()
+            .map(|error_message| WrittenLink::Message(error_message))
+            .chain((self.dropped > 0).then_some(WrittenLink::Omitted(self.dropped)))
+            .chain(once(WrittenLink::Message(root_cause)))
+    }
+}
+
+enum WrittenLink {
+    Message(&'static str),
+    Omitted(usize)
+}
+
+impl Display for WrittenLink {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WrittenLink::Message(error_message) => write!(f, "{error_message}"),
+            WrittenLink::Omitted(1) => write!(f, "[1 link omitted]"),
+            WrittenLink::Omitted(dropped) => write!(f, "[{dropped} links omitted]")
+        }
+    }
+}
+
+/// Writes as an `ErrorLink_` with nothing captured does, without allocating.
+impl<const CAPACITY: usize> Display for StaticLink<CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            for (link_number, written_link) in self.written_links().enumerate() {
+                if link_number > 0 {
+                    write!(f, ": ")?;
+                }
+                write!(f, "{written_link}")?;
+            }
+            return Ok(());
+        }
+        writeln!(f, "An error occurred.")?;
+        let mut last_number = 0;
+        for (link_number, written_link) in self.written_links().enumerate() {
+            writeln!(f, "Link no. {link_number}: {written_link}")?;
+            last_number = link_number;
+        }
+        writeln!(f, "Approximate backtrace of link no. {last_number}:")?;
+        if is_deterministic() {
+            writeln!(f, "<backtrace>")
+        } else {
+            write!(f, "{NoCapture}")
+        }
+    }
+}
+
+impl<const CAPACITY: usize> core::error::Error for StaticLink<CAPACITY> {}
+
+/// Nothing is captured where a `StaticLink` started, so neither is anything for the `ErrorLink_`.
+/// Only the message of the top link, and that of any dropped links, are allocated.
+impl<const CAPACITY: usize> From<StaticLink<CAPACITY>> for ErrorLink_<String> {
+    fn from(static_link: StaticLink<CAPACITY>) -> Self {
+        let mut written_links = static_link.written_links().rev();
+        let root_cause = written_links.next().expect("has at least the root cause");
+        let error_link = ErrorLink_::<LinkMessage>(
+            link_message(root_cause), NextLink::None(EndCapture::nothing()), Attachments::new()
+        );
+        let error_link = written_links.fold(error_link, |error_link, written_link| {
+            error_link.link::<LinkMessage>(link_message(written_link))
+        });
+        ErrorLink_(error_link.0.into_owned(), error_link.1, error_link.2)
+    }
+}
+
+fn link_message(written_link: WrittenLink) -> LinkMessage {
+    match written_link {
+        WrittenLink::Message(error_message) => Cow::Borrowed(error_message),
+        WrittenLink::Omitted(_) => Cow::Owned(written_link.to_string())
+    }
+}
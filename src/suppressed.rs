@@ -0,0 +1,87 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::marker::PhantomData;
+use crate::{Attachments, ErrorLink_, ErrorLinkable, LinkMessage};
+use crate::error_link_::as_slink;
+
+/// Errors that occurred while handling the link they are attached to.
+pub(crate) struct Suppressed(Vec<ErrorLink_<LinkMessage>>);
+
+impl Suppressed {
+    pub(crate) fn of(attachments: &Attachments) -> &[ErrorLink_<LinkMessage>] {
+        attachments.get::<Suppressed>().map_or(&[], |suppressed| &suppressed.0)
+    }
+}
+
+impl<Payload: Display> ErrorLink_<Payload> {
+    /// Attaches an error that occurred while handling this one, e.g., a failed rollback, so that
+    /// it is reported separately from the causal chain rather than dropped.
+    pub fn suppress<FromPayload: Display + 'static>(mut self, error_link: ErrorLink_<FromPayload>) -> Self {
+        let error_link = error_link.into_lower();
+        match self.2.get_mut::<Suppressed>() {
+            Some(suppressed) => suppressed.0.push(error_link),
+            None => self.2.insert(Suppressed(vec![error_link]))
+        }
+        self
+    }
+
+    pub fn suppressed(&self) -> &[ErrorLink_<LinkMessage>] {
+        Suppressed::of(&self.2)
+    }
+}
+
+/// Guard that runs a fallible cleanup upon `finish`. Should it be dropped instead, e.g., when
+/// returning early with `?`, the cleanup still runs, but only `finish` returns its error. Otherwise
+/// the error is logged as a warning, with the `log` or `tracing` feature, or else discarded.
+#[must_use = "the error of the cleanup is only returned by `finish`"]
+pub struct Deferred<Cleanup, ErrorVariant>(Option<Cleanup>, PhantomData<fn() -> ErrorVariant>)
+where
+    Cleanup: FnOnce() -> Result<(), ErrorVariant>,
+    ErrorVariant: Display;
+
+pub fn defer<Cleanup, ErrorVariant>(cleanup: Cleanup) -> Deferred<Cleanup, ErrorVariant>
+where
+    Cleanup: FnOnce() -> Result<(), ErrorVariant>,
+    ErrorVariant: Display
+{
+    Deferred(Some(cleanup), PhantomData)
+}
+
+impl<Cleanup, ErrorVariant> Deferred<Cleanup, ErrorVariant>
+where
+    Cleanup: FnOnce() -> Result<(), ErrorVariant>,
+    ErrorVariant: ErrorLinkable<ErrorVariant, String>
+{
+    /// Runs the cleanup. Should `result` already be an error, a failed cleanup is suppressed
+    /// by it, otherwise the failed cleanup becomes the error.
+    pub fn finish<OkVariant, Payload: From<String> + Display>(
+        mut self, result: Result<OkVariant, ErrorLink_<Payload>>
+    ) -> Result<OkVariant, ErrorLink_<Payload>> {
+        let cleanup = self.0.take().expect("only taken here or on drop");
+        match (result, cleanup()) {
+            (result, Ok(())) => result,
+            (Ok(_), Err(cleanup_error)) => Err(as_slink(cleanup_error).as_link()),
+            (Err(error_link), Err(cleanup_error)) => Err(error_link.suppress(as_slink(cleanup_error)))
+        }
+    }
+}
+
+impl<Cleanup, ErrorVariant> Drop for Deferred<Cleanup, ErrorVariant>
+where
+    Cleanup: FnOnce() -> Result<(), ErrorVariant>,
+    ErrorVariant: Display
+{
+    fn drop(&mut self) {
+        let Some(cleanup) = self.0.take() else {
+            return;
+        };
+        if let Err(_cleanup_error) = cleanup() {
+            #[cfg(feature = "log")]
+            log::warn!(target: "micro_errors", "A deferred cleanup failed without `finish`: {_cleanup_error}");
+            #[cfg(feature = "tracing")]
+            tracing::warn!(target: "micro_errors", "A deferred cleanup failed without `finish`: {_cleanup_error}");
+        }
+    }
+}
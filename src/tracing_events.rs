@@ -0,0 +1,51 @@
+use std::fmt::Display;
+use tracing_error::{SpanTrace, SpanTraceStatus};
+use crate::{Attachments, ErrorLink_};
+
+/// Only attaches a `SpanTrace` where it can be captured, i.e., under a `tracing_error::ErrorLayer`.
+pub(crate) fn capture_span_trace(attachments: &mut Attachments) {
+    let span_trace = SpanTrace::capture();
+    if span_trace.status() == SpanTraceStatus::CAPTURED {
+        attachments.insert(span_trace);
+    }
+}
+
+/// `tracing::event!` needs its level and target as constants, so the level is matched on and the
+/// target is fixed.
+macro_rules! event_at {
+    ($level:expr, $($event:tt)+) => {
+        match $level {
+            tracing::Level::ERROR => tracing::event!(target: "micro_errors", tracing::Level::ERROR, $($event)+),
+            tracing::Level::WARN => tracing::event!(target: "micro_errors", tracing::Level::WARN, $($event)+),
+            tracing::Level::INFO => tracing::event!(target: "micro_errors", tracing::Level::INFO, $($event)+),
+            tracing::Level::DEBUG => tracing::event!(target: "micro_errors", tracing::Level::DEBUG, $($event)+),
+            tracing::Level::TRACE => tracing::event!(target: "micro_errors", tracing::Level::TRACE, $($event)+)
+        }
+    };
+}
+
+impl<Payload: Display> ErrorLink_<Payload> {
+    /// Emits an event per link at `level`, whose fields hold the number and the message of the
+    /// link, instead of the whole report being formatted into one event's message. The event of
+    /// link no. 0 also holds the number of links, and the span trace. The events' target is always
+    /// `micro_errors`, e.g., enabled with `RUST_LOG=micro_errors=warn`, whichever module records them.
+    pub fn record(&self, level: tracing::Level) {
+        let link_count = self.links().count() + 1;
+        event_at!(
+            level,
+            error.link_number = 0,
+            error.message = %self.0,
+            error.link_count = link_count,
+            error.span_trace = self.request_ref::<SpanTrace>().map(tracing::field::display),
+            "{}", self.0
+        );
+        for (link_number, error_link) in (1..).zip(self.links()) {
+            event_at!(
+                level,
+                error.link_number = link_number,
+                error.message = %error_link.0,
+                "{}", error_link.0
+            );
+        }
+    }
+}